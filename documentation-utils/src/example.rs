@@ -1,7 +1,6 @@
+use material_yewi::hooks::use_id;
 use material_yewi::typography::Typography;
 use material_yewi::typography::TypographyVariant;
-use once_cell::sync::Lazy;
-use std::sync::{Arc, Mutex};
 use stylist::{ast::sheet, yew::use_style};
 use yew::prelude::*;
 
@@ -15,19 +14,6 @@ pub struct ExampleProps {
     pub children: Children,
 }
 
-fn get_next_style_id() -> u64 {
-    static CTR: Lazy<Arc<Mutex<u64>>> = Lazy::new(Arc::default);
-    let mut ctr = CTR.lock().expect("Failed to lock Rng.");
-
-    *ctr += 1;
-    *ctr
-}
-
-#[hook]
-fn use_unique_name(suggestion: &'static str) -> UseStateHandle<String> {
-    use_state(move || format!("{}-{}", suggestion, get_next_style_id()))
-}
-
 #[function_component]
 pub fn Example(props: &ExampleProps) -> Html {
     let tab_styles = use_style!(/* "tab", */
@@ -86,9 +72,10 @@ pub fn Example(props: &ExampleProps) -> Html {
     let code_styles = use_style(/* "tab-code", */ mk_tab_content_active(1));
     let result_style = use_style(/* "tab-results", */ mk_tab_content_active(2));
 
-    let code_id = use_unique_name("code-sample");
-    let tabgroup_id = use_unique_name("code-example");
-    let results_id = use_unique_name("code-results");
+    let example_id = use_id(None);
+    let code_id = format!("{}-code-sample", example_id);
+    let tabgroup_id = format!("{}-code-example", example_id);
+    let results_id = format!("{}-code-results", example_id);
 
     ::yew::html! {
         <div class={classes![wrapper_style]}>
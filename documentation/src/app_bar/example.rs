@@ -1,5 +1,6 @@
 use material_yewi::app_bar::{AppBar, AppBarPosition};
 use material_yewi::button::{Button, ButtonColor};
+use material_yewi::icon_button::{IconButton, IconButtonEdge};
 use material_yewi::toolbar::Toolbar;
 use material_yewi::typography::{Typography, TypographyVariant};
 use stylist::ast::sheet;
@@ -11,9 +12,9 @@ pub fn render() -> Html {
         // FIXME: Absolute position necessary cause we don't iframe in demos correctly
         <AppBar position={AppBarPosition::Static}>
             <Toolbar>
-                <Button color={ButtonColor::Inherit}>
+                <IconButton aria_label="menu" color={ButtonColor::Inherit} edge={IconButtonEdge::Start}>
                     <Menu />
-                </Button>
+                </IconButton>
                 <Typography class={sheet!(flex-grow: 1;)} variant={TypographyVariant::H6}>
                     {"News"}
                 </Typography>
@@ -5,6 +5,8 @@ use yew::prelude::*;
 #[function_component]
 pub fn Doc() -> Html {
     let example = document_example! {"example.rs"};
+    let scroll_example = document_example! {"scroll_example.rs"};
+    let top_app_bar_example = document_example! {"top_app_bar_example.rs"};
     html! {
         <>
             <Typography variant={TypographyVariant::H2}>
@@ -14,6 +16,14 @@ pub fn Doc() -> Html {
                 {"Below you can find examples showing the "}<pre style="display:inline;">{"AppBar"}</pre>{" component."}
             </Typography>
             {example}
+            <Typography variant={TypographyVariant::Paragraph}>
+                {"App bars can hide while scrolling down and only be elevated while content is scrolled under them."}
+            </Typography>
+            {scroll_example}
+            <Typography variant={TypographyVariant::Paragraph}>
+                {"The "}<pre style="display:inline;">{"TopAppBar"}</pre>{" component lays out the Material 3 top app bars. The headline of the medium and large variants collapses while scrolling."}
+            </Typography>
+            {top_app_bar_example}
         </>
     }
 }
@@ -0,0 +1,35 @@
+use material_yewi::app_bar::{AppBar, AppBarPosition};
+use material_yewi::hooks::ScrollTarget;
+use material_yewi::toolbar::Toolbar;
+use material_yewi::typography::{Typography, TypographyVariant};
+use yew::{function_component, html, use_node_ref, Html};
+
+#[function_component]
+fn ScrollingPage() -> Html {
+    let container = use_node_ref();
+    html! {
+        <div ref={&container} style="height: 300px; overflow-y: auto;">
+            <AppBar
+                position={AppBarPosition::Sticky}
+                hide_on_scroll={true}
+                elevate_on_scroll={true}
+                scroll_target={ScrollTarget::Element(container.clone())}
+            >
+                <Toolbar>
+                    <Typography variant={TypographyVariant::H6}>{"Scroll me"}</Typography>
+                </Toolbar>
+            </AppBar>
+            {
+                for (1..=30).map(|line| html! {
+                    <Typography variant={TypographyVariant::Paragraph}>
+                        {format!("Line {} of the scrolling content.", line)}
+                    </Typography>
+                })
+            }
+        </div>
+    }
+}
+
+pub fn render() -> Html {
+    html! { <ScrollingPage /> }
+}
@@ -0,0 +1,31 @@
+use material_yewi::app_bar::AppBarPosition;
+use material_yewi::hooks::ScrollTarget;
+use material_yewi::top_app_bar::{TopAppBar, TopAppBarVariant};
+use material_yewi::typography::{Typography, TypographyVariant};
+use yew::{function_component, html, use_node_ref, Html};
+
+#[function_component]
+fn LargeTopAppBarPage() -> Html {
+    let container = use_node_ref();
+    html! {
+        <div ref={&container} style="height: 300px; overflow-y: auto;">
+            <TopAppBar
+                variant={TopAppBarVariant::Large}
+                position={AppBarPosition::Sticky}
+                title={html! {"Large top app bar"}}
+                scroll_target={ScrollTarget::Element(container.clone())}
+            />
+            {
+                for (1..=30).map(|line| html! {
+                    <Typography variant={TypographyVariant::Paragraph}>
+                        {format!("Line {} of the scrolling content.", line)}
+                    </Typography>
+                })
+            }
+        </div>
+    }
+}
+
+pub fn render() -> Html {
+    html! { <LargeTopAppBarPage /> }
+}
@@ -25,6 +25,9 @@ pub fn render() -> Html {
             <Button disabled={true}>
                 {"Disabled"}
             </Button>
+            <Button href="https://github.com/WorldSEnder/material-yewi" target="_blank" rel="noopener">
+                {"Link"}
+            </Button>
         </ButtonRow>
     }
 }
@@ -40,7 +43,7 @@ struct WrapperProps {
 fn ButtonRow(props: &WrapperProps) -> Html {
     // A Small utility component for documentation, with some extra visual styling
     let wrapper_class = stylist::yew::use_style!(
-        & > button { margin: 8px; }
+        & > button, & > a { margin: 8px; }
     );
     html! {
         <div class={classes![wrapper_class]}>
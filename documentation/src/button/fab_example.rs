@@ -0,0 +1,28 @@
+use material_yewi::button::{ButtonColor, ButtonSize};
+use material_yewi::fab::{Fab, FabVariant};
+use yew::{html, Html};
+use yew_feather::navigation::Navigation;
+use yew_feather::plus::Plus;
+
+pub fn render() -> Html {
+    html! {
+        <div style="display: flex; align-items: center; gap: 16px; padding: 8px;">
+            <Fab color={ButtonColor::Primary} aria_label="add">
+                <Plus />
+            </Fab>
+            <Fab color={ButtonColor::Secondary} size={ButtonSize::Medium} aria_label="add">
+                <Plus />
+            </Fab>
+            <Fab size={ButtonSize::Small} aria_label="add">
+                <Plus />
+            </Fab>
+            <Fab variant={FabVariant::Extended} color={ButtonColor::Success}>
+                <span style="display: inherit; margin-right: 8px;"><Navigation /></span>
+                {"Navigate"}
+            </Fab>
+            <Fab disabled={true} aria_label="add">
+                <Plus />
+            </Fab>
+        </div>
+    }
+}
@@ -0,0 +1,43 @@
+use material_yewi::button::{Button, ButtonSize, ButtonVariant};
+use yew::{html, Html};
+use yew_feather::send::Send;
+use yew_feather::trash::Trash;
+
+pub fn render() -> Html {
+    html! {
+        <ButtonRow>
+            <Button variant={ButtonVariant::Outlined} start_icon={html! { <Trash /> }}>
+                {"Delete"}
+            </Button>
+            <Button variant={ButtonVariant::Contained} end_icon={html! { <Send /> }}>
+                {"Send"}
+            </Button>
+            <Button size={ButtonSize::Small} start_icon={html! { <Trash /> }}>
+                {"Small"}
+            </Button>
+            <Button size={ButtonSize::Large} variant={ButtonVariant::Contained} end_icon={html! { <Send /> }}>
+                {"Large"}
+            </Button>
+        </ButtonRow>
+    }
+}
+
+use yew::{classes, function_component, Children, Properties};
+
+#[derive(Clone, PartialEq, Properties)]
+struct WrapperProps {
+    children: Children,
+}
+
+#[function_component]
+fn ButtonRow(props: &WrapperProps) -> Html {
+    // A Small utility component for documentation, with some extra visual styling
+    let wrapper_class = stylist::yew::use_style!(
+        & > button, & > a { margin: 8px; }
+    );
+    html! {
+        <div class={classes![wrapper_class]}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,74 @@
+use material_yewi::button::{ButtonProperties, ButtonVariant};
+use material_yewi::loading_button::{LoadingButton, LoadingPosition};
+use yew::{function_component, html, props, use_state, Callback, Html};
+use yew_feather::save::Save;
+use yew_feather::send::Send;
+
+#[function_component]
+fn LoadingButtons() -> Html {
+    let loading = use_state(|| false);
+    let toggle = {
+        let loading = loading.clone();
+        Callback::from(move |_| loading.set(!*loading))
+    };
+    html! {
+        <>
+            <label>
+                <input type="checkbox" checked={*loading} onclick={toggle} />
+                {"Loading"}
+            </label>
+            <ButtonRow>
+                <LoadingButton
+                    loading={*loading}
+                    button={props!(ButtonProperties { variant: ButtonVariant::Outlined })}
+                >
+                    {"Submit"}
+                </LoadingButton>
+                <LoadingButton
+                    loading={*loading}
+                    loading_position={LoadingPosition::Start}
+                    button={props!(ButtonProperties {
+                        start_icon: Some(html! { <Save /> }),
+                        variant: ButtonVariant::Contained,
+                    })}
+                >
+                    {"Save"}
+                </LoadingButton>
+                <LoadingButton
+                    loading={*loading}
+                    loading_position={LoadingPosition::End}
+                    button={props!(ButtonProperties {
+                        end_icon: Some(html! { <Send /> }),
+                        variant: ButtonVariant::Contained,
+                    })}
+                >
+                    {"Send"}
+                </LoadingButton>
+            </ButtonRow>
+        </>
+    }
+}
+
+pub fn render() -> Html {
+    html! { <LoadingButtons /> }
+}
+
+use yew::{classes, Children, Properties};
+
+#[derive(Clone, PartialEq, Properties)]
+struct WrapperProps {
+    children: Children,
+}
+
+#[function_component]
+fn ButtonRow(props: &WrapperProps) -> Html {
+    // A Small utility component for documentation, with some extra visual styling
+    let wrapper_class = stylist::yew::use_style!(
+        & > button, & > a { margin: 8px; }
+    );
+    html! {
+        <div class={classes![wrapper_class]}>
+            { for props.children.iter() }
+        </div>
+    }
+}
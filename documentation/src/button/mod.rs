@@ -5,6 +5,9 @@ use yew::prelude::*;
 #[function_component]
 pub fn Doc() -> Html {
     let example = document_example! {"example.rs"};
+    let icons_example = document_example! {"icons_example.rs"};
+    let loading_example = document_example! {"loading_example.rs"};
+    let fab_example = document_example! {"fab_example.rs"};
     html! {
         <>
             <Typography variant={TypographyVariant::H2}>
@@ -14,6 +17,18 @@ pub fn Doc() -> Html {
                 {"Below you can find examples showing the "}<pre style="display:inline;">{"Button"}</pre>{" component."}
             </Typography>
             {example}
+            <Typography variant={TypographyVariant::Paragraph}>
+                {"Buttons can show an icon before or after their label, with spacing adjusted to their size."}
+            </Typography>
+            {icons_example}
+            <Typography variant={TypographyVariant::Paragraph}>
+                {"A "}<pre style="display:inline;">{"LoadingButton"}</pre>{" shows progress in place of its label or one of its icons, and is disabled while loading."}
+            </Typography>
+            {loading_example}
+            <Typography variant={TypographyVariant::Paragraph}>
+                {"A floating action button performs the primary action of a screen. Extended buttons fit a label next to the icon."}
+            </Typography>
+            {fab_example}
         </>
     }
 }
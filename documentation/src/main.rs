@@ -1,4 +1,5 @@
 use material_yewi::app_bar::{AppBar, AppBarColor, AppBarPosition};
+use material_yewi::hooks::IdProvider;
 use material_yewi::link::Link;
 use material_yewi::toolbar::Toolbar;
 use material_yewi::typography::{Typography, TypographyVariant};
@@ -101,8 +102,10 @@ fn Docs() -> Html {
                     position: relative;
                 }
             )} />
-            {page_header()}
-            <DocSwitch render={Switch::render(switch)} />
+            <IdProvider>
+                {page_header()}
+                <DocSwitch render={Switch::render(switch)} />
+            </IdProvider>
         </BrowserRouter>
     }
 }
@@ -42,6 +42,12 @@ pub fn render() -> Html {
                 neque doloribus, cupiditate numquam dignissimos laborum fugiat deleniti? Eum
                 quasi quidem quibusdam."}
             </Typography>
+            <Typography variant={TypographyVariant::Body2} gutter_bottom={true} line_clamp={2}>
+                {"line_clamp=2. Lorem ipsum dolor sit amet, consectetur adipisicing elit. Quos
+                blanditiis tenetur unde suscipit, quam beatae rerum inventore consectetur,
+                neque doloribus, cupiditate numquam dignissimos laborum fugiat deleniti? Eum
+                quasi quidem quibusdam."}
+            </Typography>
             <Typography variant={TypographyVariant::Button} gutter_bottom={true}>
                 {"button text"}
             </Typography>
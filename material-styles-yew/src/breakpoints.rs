@@ -0,0 +1,78 @@
+/// The named viewport widths at which layouts adapt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Extra small, phones
+    Xs,
+    /// Small, tablets
+    Sm,
+    /// Medium, small laptops
+    Md,
+    /// Large, desktops
+    Lg,
+    /// Extra large, large screens
+    Xl,
+}
+
+/// The minimum viewport width of each [`Breakpoint`], in pixels.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakpoints {
+    pub xs: u32,
+    pub sm: u32,
+    pub md: u32,
+    pub lg: u32,
+    pub xl: u32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            xs: 0,
+            sm: 600,
+            md: 900,
+            lg: 1200,
+            xl: 1536,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// The minimum viewport width of the breakpoint, in pixels.
+    pub fn value(&self, key: Breakpoint) -> u32 {
+        match key {
+            Breakpoint::Xs => self.xs,
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+
+    /// A media query matching viewports at least as wide as the breakpoint.
+    ///
+    /// ```
+    /// # use material_styles_yew::{Breakpoint, Breakpoints};
+    /// assert_eq!(Breakpoints::default().up(Breakpoint::Sm), "(min-width:600px)");
+    /// ```
+    pub fn up(&self, key: Breakpoint) -> String {
+        format!("(min-width:{}px)", self.value(key))
+    }
+
+    /// A media query matching viewports narrower than the breakpoint.
+    ///
+    /// ```
+    /// # use material_styles_yew::{Breakpoint, Breakpoints};
+    /// assert_eq!(Breakpoints::default().down(Breakpoint::Md), "(max-width:899.95px)");
+    /// ```
+    pub fn down(&self, key: Breakpoint) -> String {
+        // Subtract a fraction of a pixel, so that exactly one of `up` and `down` matches
+        let hundredths = self.value(key) as i64 * 100 - 5;
+        let sign = if hundredths < 0 { "-" } else { "" };
+        let hundredths = hundredths.abs();
+        format!(
+            "(max-width:{}{}.{:02}px)",
+            sign,
+            hundredths / 100,
+            hundredths % 100
+        )
+    }
+}
@@ -136,6 +136,62 @@ impl CssColor {
         }
     }
 
+    /// The red, green and blue components in the range [0, 1], and the alpha value
+    fn to_rgba(self) -> (f32, f32, f32, f32) {
+        match self {
+            CssColor::RGB { r, g, b, a } => (r, g, b, a),
+            CssColor::HSL { h, s, l, a } => {
+                let chroma = (1f32 - (2f32 * l - 1f32).abs()) * s;
+                let sector = h / 60f32;
+                let x = chroma * (1f32 - (sector.rem_euclid(2f32) - 1f32).abs());
+                let (r, g, b) = match sector as u32 {
+                    0 => (chroma, x, 0f32),
+                    1 => (x, chroma, 0f32),
+                    2 => (0f32, chroma, x),
+                    3 => (0f32, x, chroma),
+                    4 => (x, 0f32, chroma),
+                    _ => (chroma, 0f32, x),
+                };
+                let m = l - chroma / 2f32;
+                (r + m, g + m, b + m, a)
+            }
+        }
+    }
+
+    /// The relative luminance as defined by WCAG 2, between 0 for black and 1 for white. The
+    /// alpha value is ignored.
+    ///
+    /// ```
+    /// # use material_styles_yew::CssColor;
+    /// assert_eq!(CssColor::rgb(0, 0, 0).luminance(), 0.0);
+    /// assert!((CssColor::hsl(0.0, 0.0, 1.0).luminance() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn luminance(&self) -> f32 {
+        let linear = |c: f32| {
+            if c <= 0.03928f32 {
+                c / 12.92f32
+            } else {
+                ((c + 0.055f32) / 1.055f32).powf(2.4f32)
+            }
+        };
+        let (r, g, b, _) = self.to_rgba();
+        0.2126f32 * linear(r) + 0.7152f32 * linear(g) + 0.0722f32 * linear(b)
+    }
+
+    /// The contrast ratio of two colors as defined by WCAG 2, between 1 and 21.
+    ///
+    /// ```
+    /// # use material_styles_yew::CssColor;
+    /// let black = CssColor::rgb(0, 0, 0);
+    /// let white = CssColor::rgb(255, 255, 255);
+    /// assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+    /// assert_eq!(white.contrast_ratio(&white), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: &CssColor) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05f32) / (a.min(b) + 0.05f32)
+    }
+
     pub fn to_css_value(&self) -> String {
         let omit_alpha = |a: f32| a >= 0.99999f32; // alpha clamped to [0, 1] anyway
         match self {
@@ -0,0 +1,92 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use stylist::ast::Sheet;
+use stylist::manager::StyleManager;
+use stylist::GlobalStyle;
+use yew::functional::Hook;
+use yew::{use_context, use_memo, HookContext};
+
+/// A set of css keyframes, available under a generated name that does not collide with
+/// other keyframes.
+///
+/// Keyframes are usually declared once, in a `lazy_static!`, and registered by the components
+/// using them with [`use_keyframes`]. Each set of keyframes is only ever injected once per
+/// [`StyleManager`], no matter how many components use it.
+///
+/// The name is derived from the prefix and the content of the keyframes, so that it is the
+/// same on the server and in the browser, independent of the order keyframes are created in.
+///
+/// ```
+/// # use material_styles_yew::Keyframes;
+/// use stylist::ast::sheet;
+/// let fade = Keyframes::new("fade", |name| {
+///     sheet!(
+///         "@keyframes ${name} {
+///             0% { opacity: 0; }
+///             100% { opacity: 1; }
+///         }",
+///         name = name
+///     )
+/// });
+/// let other_fade = Keyframes::new("fade", |name| sheet!("@keyframes ${name} {}", name = name));
+/// let same_fade = Keyframes::new("fade", |name| {
+///     sheet!(
+///         "@keyframes ${name} {
+///             0% { opacity: 0; }
+///             100% { opacity: 1; }
+///         }",
+///         name = name
+///     )
+/// });
+/// assert!(fade.name().starts_with("fade-"));
+/// assert_ne!(fade.name(), other_fade.name());
+/// assert_eq!(fade.name(), same_fade.name());
+/// ```
+#[derive(Debug)]
+pub struct Keyframes {
+    name: String,
+    sheet: Sheet,
+}
+
+impl Keyframes {
+    /// Create new keyframes, named after `prefix`. The `@keyframes` rule is built by `frames`
+    /// from the generated name.
+    pub fn new(prefix: &str, frames: impl Fn(&str) -> Sheet) -> Self {
+        // The content is hashed with the prefix standing in for the name
+        let mut hasher = DefaultHasher::new();
+        prefix.hash(&mut hasher);
+        format!("{:?}", frames(prefix)).hash(&mut hasher);
+        let name = format!("{}-{:016x}", prefix, hasher.finish());
+        let sheet = frames(&name);
+        Self { name, sheet }
+    }
+
+    /// The generated name, to be used as `animation-name` in sheets.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Register the keyframes with a style manager. The manager only injects keyframes with the
+    /// same content once, and forgets them when it is dropped.
+    pub fn register(&self, manager: &StyleManager) {
+        GlobalStyle::new_with_manager(self.sheet.clone(), manager.clone())
+            .expect("keyframes to be valid css");
+    }
+}
+
+/// Register the keyframes with the style manager of the current context and return their name.
+pub fn use_keyframes(keyframes: &'static Keyframes) -> impl Hook<Output = &'static str> {
+    struct TheHook(&'static Keyframes);
+    impl Hook for TheHook {
+        type Output = &'static str;
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let manager = use_context::<StyleManager>().run(ctx).unwrap_or_default();
+            let keyframes = self.0;
+            // Registered once per component and manager, not on every render
+            use_memo(|manager| keyframes.register(manager), manager).run(ctx);
+            keyframes.name()
+        }
+    }
+    TheHook(keyframes)
+}
@@ -1,4 +1,5 @@
 use dependent_map::{DebugEntry, DynClone, DynPartialEq, HashableAny};
+use std::collections::BTreeMap;
 use std::convert::TryInto;
 use std::fmt::{Debug, Display};
 use std::hash::Hasher;
@@ -9,8 +10,14 @@ use stylist::ast::Sheet;
 use yew::functional::Hook;
 use yew::{use_context, use_memo, HookContext};
 
+mod breakpoints;
 mod color;
+mod keyframes;
+mod motion;
+pub use breakpoints::{Breakpoint, Breakpoints};
 pub use color::*;
+pub use keyframes::{use_keyframes, Keyframes};
+pub use motion::Motion;
 
 #[derive(Debug)]
 pub struct Typography {
@@ -106,11 +113,13 @@ pub struct ThemeContents {
     pub palette: Palette,
     pub shadows: Shadows,
     pub spacing: Spacing,
+    /// Defaults to following the `prefers-reduced-motion` preference of the user
+    pub motion: Motion,
     // transitions?: unknown;
     pub components: Components,
     // mixins?: unknown;
     pub typography: Typography,
-    // zIndex?: unknown;
+    pub z_index: ZIndex,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -126,12 +135,37 @@ impl Default for Shape {
     }
 }
 
-#[derive(Default, Clone, Debug, PartialEq)]
-pub struct Breakpoints {}
-
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Direction {}
 
+/// The stacking order of components floating above the content, from bottom to top.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZIndex {
+    pub mobile_stepper: u32,
+    pub fab: u32,
+    pub speed_dial: u32,
+    pub app_bar: u32,
+    pub drawer: u32,
+    pub modal: u32,
+    pub snackbar: u32,
+    pub tooltip: u32,
+}
+
+impl Default for ZIndex {
+    fn default() -> Self {
+        Self {
+            mobile_stepper: 1000,
+            fab: 1050,
+            speed_dial: 1050,
+            app_bar: 1100,
+            drawer: 1200,
+            modal: 1300,
+            snackbar: 1400,
+            tooltip: 1500,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct ColorSpec {
     pub light: CssColor,
@@ -246,12 +280,44 @@ where
     }
 }
 
+/// Whether the palette is meant for a light or a dark background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteMode {
+    Light,
+    Dark,
+}
+
+impl Default for PaletteMode {
+    fn default() -> Self {
+        Self::Light
+    }
+}
+
+/// Selects one of the colors of a [`Palette`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PaletteColor {
+    Primary,
+    Secondary,
+    Error,
+    Warning,
+    Info,
+    Success,
+    /// A color added to [`Palette::custom`] under the given key
+    Custom(&'static str),
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Palette {
+    /// Colors are not adjusted to the mode, but components adapt their appearance to it
+    pub mode: PaletteMode,
     pub primary: ColorSpec,
     pub secondary: ColorSpec,
     pub error: ColorSpec,
     pub warning: ColorSpec,
+    pub info: ColorSpec,
+    pub success: ColorSpec,
+    /// Additional colors, selected with [`PaletteColor::Custom`]
+    pub custom: BTreeMap<String, ColorSpec>,
     //
     pub text: TextColorSpec,
     //
@@ -263,6 +329,7 @@ pub struct Palette {
 impl Default for Palette {
     fn default() -> Self {
         Self {
+            mode: Default::default(),
             primary: ColorSpec {
                 light: "#7986cb".try_into().expect(""),
                 main: "#3f51b5".try_into().expect(""),
@@ -287,6 +354,19 @@ impl Default for Palette {
                 dark: "#f57c00".try_into().expect(""),
                 contrast: CssColor::rgba(0, 0, 0, 0.87),
             },
+            info: ColorSpec {
+                light: "#4fc3f7".try_into().expect(""),
+                main: "#29b6f6".try_into().expect(""),
+                dark: "#0288d1".try_into().expect(""),
+                contrast: CssColor::rgba(0, 0, 0, 0.87),
+            },
+            success: ColorSpec {
+                light: "#81c784".try_into().expect(""),
+                main: "#66bb6a".try_into().expect(""),
+                dark: "#388e3c".try_into().expect(""),
+                contrast: CssColor::rgba(0, 0, 0, 0.87),
+            },
+            custom: BTreeMap::new(),
             text: Default::default(),
             actions: Default::default(),
             background: Default::default(),
@@ -296,9 +376,50 @@ impl Default for Palette {
 }
 
 impl Palette {
-    pub fn contrast_text_color(&self, _background: CssColor) -> CssColor {
-        // FIXME: return light color for dark background
-        self.text.primary
+    /// Look up a color of the palette. Only custom colors can be missing.
+    ///
+    /// ```
+    /// # use material_styles_yew::{CssColor, ColorSpec, Palette, PaletteColor};
+    /// let mut palette = Palette::default();
+    /// let teal = CssColor::rgb(0, 128, 128);
+    /// let spec = ColorSpec { light: teal, main: teal, dark: teal, contrast: CssColor::rgb(255, 255, 255) };
+    /// palette.custom.insert("teal".to_string(), spec.clone());
+    /// assert_eq!(palette.color(PaletteColor::Custom("teal")), Some(&spec));
+    /// assert_eq!(palette.color(PaletteColor::Error), Some(&palette.error));
+    /// assert_eq!(palette.color(PaletteColor::Custom("missing")), None);
+    /// ```
+    pub fn color(&self, color: PaletteColor) -> Option<&ColorSpec> {
+        match color {
+            PaletteColor::Primary => Some(&self.primary),
+            PaletteColor::Secondary => Some(&self.secondary),
+            PaletteColor::Error => Some(&self.error),
+            PaletteColor::Warning => Some(&self.warning),
+            PaletteColor::Info => Some(&self.info),
+            PaletteColor::Success => Some(&self.success),
+            PaletteColor::Custom(key) => self.custom.get(key),
+        }
+    }
+
+    /// A text color that is readable on the given background: white, if its contrast ratio with
+    /// the background is at least 3, otherwise the primary text color.
+    ///
+    /// ```
+    /// # use std::convert::TryInto;
+    /// # use material_styles_yew::{CssColor, Palette};
+    /// let palette = Palette::default();
+    /// let white = CssColor::rgb(255, 255, 255);
+    /// assert_eq!(palette.contrast_text_color(palette.primary.main), white);
+    /// assert_eq!(palette.contrast_text_color(palette.warning.main), palette.text.primary);
+    /// let gray_300 = "#e0e0e0".try_into().unwrap();
+    /// assert_eq!(palette.contrast_text_color(gray_300), palette.text.primary);
+    /// ```
+    pub fn contrast_text_color(&self, background: CssColor) -> CssColor {
+        let white = CssColor::rgb(255, 255, 255);
+        if background.contrast_ratio(&white) >= 3f32 {
+            white
+        } else {
+            self.text.primary
+        }
     }
 }
 
@@ -387,6 +508,14 @@ impl Default for Theme {
     }
 }
 
+impl From<ThemeContents> for Theme {
+    fn from(contents: ThemeContents) -> Self {
+        Self {
+            inner: Rc::new(contents),
+        }
+    }
+}
+
 impl Deref for Theme {
     type Target = ThemeContents;
 
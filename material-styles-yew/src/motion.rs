@@ -0,0 +1,71 @@
+use stylist::ast::{sheet, Sheet};
+
+/// How much components animate, e.g. their ripples and transitions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Motion {
+    /// Follow the `prefers-reduced-motion` preference of the user, behaving like
+    /// [`Motion::Reduced`] if the user prefers reduced motion and like [`Motion::Full`] otherwise.
+    System,
+    /// All animations and transitions are shown.
+    Full,
+    /// Ripples neither grow nor pulsate and components do not transition between states.
+    /// Ripples still fade out.
+    Reduced,
+    /// Nothing is animated.
+    None,
+}
+
+impl Default for Motion {
+    fn default() -> Self {
+        Self::System
+    }
+}
+
+impl Motion {
+    /// Css for a `transition` that is only shown with full motion.
+    ///
+    /// ```
+    /// # use material_styles_yew::Motion;
+    /// assert!(Motion::Reduced.transition("color 250ms").is_empty());
+    /// assert!(!Motion::Full.transition("color 250ms").is_empty());
+    /// ```
+    pub fn transition(&self, transition: &str) -> Sheet {
+        match self {
+            Self::Full => sheet!(
+                transition: ${transition};
+            ),
+            Self::System => sheet!(
+                transition: ${transition};
+                @media (prefers-reduced-motion: reduce) {
+                    & {
+                        transition: none;
+                    }
+                }
+            ),
+            Self::Reduced | Self::None => Sheet::default(),
+        }
+    }
+    /// Css for an `animation`, e.g. of a loading indicator, that is only shown with full motion.
+    ///
+    /// ```
+    /// # use material_styles_yew::Motion;
+    /// assert!(Motion::Reduced.animation("spin 750ms linear infinite").is_empty());
+    /// assert!(!Motion::Full.animation("spin 750ms linear infinite").is_empty());
+    /// ```
+    pub fn animation(&self, animation: &str) -> Sheet {
+        match self {
+            Self::Full => sheet!(
+                animation: ${animation};
+            ),
+            Self::System => sheet!(
+                animation: ${animation};
+                @media (prefers-reduced-motion: reduce) {
+                    & {
+                        animation: none;
+                    }
+                }
+            ),
+            Self::Reduced | Self::None => Sheet::default(),
+        }
+    }
+}
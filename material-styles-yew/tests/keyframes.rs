@@ -0,0 +1,64 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use lazy_static::lazy_static;
+use material_styles_yew::{use_keyframes, Keyframes};
+use stylist::ast::sheet;
+use wasm_bindgen_test::*;
+use yew::{function_component, html, Html};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+lazy_static! {
+    static ref KEYFRAMES_BLINK: Keyframes = Keyframes::new("test-blink", |name| {
+        sheet!(
+            "@keyframes ${name} {
+                0% { opacity: 0; }
+                100% { opacity: 1; }
+            }",
+            name = name
+        )
+    });
+}
+
+#[function_component]
+fn Blinking() -> Html {
+    let name = use_keyframes(&KEYFRAMES_BLINK);
+    html! { <span>{ name }</span> }
+}
+
+#[function_component]
+fn ManyBlinking() -> Html {
+    html! {
+        <>
+            <Blinking />
+            <Blinking />
+            <Blinking />
+        </>
+    }
+}
+
+/// Count the style elements in the document defining the keyframes
+fn count_definitions(name: &str) -> usize {
+    let styles = document().query_selector_all("style").unwrap();
+    (0..styles.length())
+        .filter_map(|i| styles.item(i)?.text_content())
+        .filter(|css| css.contains(&format!("@keyframes {}", name)))
+        .count()
+}
+
+#[wasm_bindgen_test]
+async fn registered_once_across_components() {
+    for _ in 0..2 {
+        let root = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&root).unwrap();
+        yew::Renderer::<ManyBlinking>::with_root(root.clone()).render();
+        TimeoutFuture::new(0).await;
+        assert_eq!(
+            root.text_content().unwrap(),
+            KEYFRAMES_BLINK.name().repeat(3)
+        );
+    }
+    assert_eq!(count_definitions(KEYFRAMES_BLINK.name()), 1);
+}
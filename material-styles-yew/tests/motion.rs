@@ -0,0 +1,52 @@
+use material_styles_yew::Motion;
+use stylist::ast::Sheet;
+
+const TRANSITION: &str = "color 150ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, opacity 250ms linear 50ms";
+
+fn css(sheet: &Sheet) -> String {
+    format!("{:?}", sheet)
+}
+
+#[test]
+fn full_motion_keeps_the_durations() {
+    let sheet = Motion::Full.transition(TRANSITION);
+    let css = css(&sheet);
+    assert!(css.contains("150ms"));
+    assert!(css.contains("250ms"));
+    assert!(css.contains("50ms"));
+    assert!(!css.contains("prefers-reduced-motion"));
+}
+
+#[test]
+fn system_motion_follows_the_user_preference() {
+    let sheet = Motion::System.transition(TRANSITION);
+    let css = css(&sheet);
+    assert!(css.contains("150ms"));
+    assert!(css.contains("250ms"));
+    assert!(css.contains("prefers-reduced-motion"));
+    assert!(css.contains("reduce"));
+    assert!(css.contains("none"));
+}
+
+#[test]
+fn reduced_motion_has_no_transition() {
+    assert!(Motion::Reduced.transition(TRANSITION).is_empty());
+    assert!(Motion::None.transition(TRANSITION).is_empty());
+}
+
+#[test]
+fn animations_follow_the_motion() {
+    let animation = "spin 750ms linear infinite";
+    assert!(css(&Motion::Full.animation(animation)).contains("750ms"));
+    assert!(!css(&Motion::Full.animation(animation)).contains("prefers-reduced-motion"));
+    let system = css(&Motion::System.animation(animation));
+    assert!(system.contains("750ms"));
+    assert!(system.contains("prefers-reduced-motion"));
+    assert!(Motion::Reduced.animation(animation).is_empty());
+    assert!(Motion::None.animation(animation).is_empty());
+}
+
+#[test]
+fn system_is_the_default() {
+    assert_eq!(Motion::default(), Motion::System);
+}
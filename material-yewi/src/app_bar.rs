@@ -1,13 +1,18 @@
 use material_styles_yew::use_theme;
 use material_styles_yew::Theme;
+use material_styles_yew::{CssColor, PaletteColor, PaletteMode};
 use stylist::ast::ScopeContent;
 use stylist::ast::{sheet, Sheet};
 use yew::function_component;
 use yew::html;
 use yew::Children;
 use yew::Html;
+use yew::NodeRef;
 use yew::Properties;
 
+use crate::attributes::ExtraAttributes;
+use crate::hooks::{use_optional_scroll_trigger, ScrollTarget, ScrollTriggerOptions};
+use crate::palette_sheets::PaletteSheets;
 use crate::paper::Paper;
 use crate::paper::PaperEdgeStyle;
 use crate::paper::PaperVariant;
@@ -42,10 +47,33 @@ impl Default for AppBarPosition {
 pub enum AppBarColor {
     Primary,
     Secondary,
+    Error,
+    Warning,
+    Info,
+    Success,
+    /// A custom color of the palette, by its key in `Palette::custom`
+    Custom(&'static str),
+    /// An explicit background color, with a text color contrasting it
+    Background(CssColor),
     Transparent,
     Inherit,
 }
 
+impl AppBarColor {
+    fn palette_color(self) -> Option<PaletteColor> {
+        match self {
+            Self::Primary => Some(PaletteColor::Primary),
+            Self::Secondary => Some(PaletteColor::Secondary),
+            Self::Error => Some(PaletteColor::Error),
+            Self::Warning => Some(PaletteColor::Warning),
+            Self::Info => Some(PaletteColor::Info),
+            Self::Success => Some(PaletteColor::Success),
+            Self::Custom(key) => Some(PaletteColor::Custom(key)),
+            Self::Background(_) | Self::Transparent | Self::Inherit => None,
+        }
+    }
+}
+
 impl Default for AppBarColor {
     fn default() -> Self {
         Self::Primary
@@ -62,6 +90,29 @@ pub struct AppBarProperties {
     pub position: AppBarPosition,
     #[prop_or_default]
     pub color: AppBarColor,
+    /// In dark mode, app bars are colored like [`Paper`] unless this is enabled.
+    #[prop_or(false)]
+    pub enable_color_on_dark: bool,
+    /// The elevation of the underlying [`Paper`], between 0 and 24.
+    #[prop_or(4)]
+    pub elevation: u8,
+    #[prop_or(PaperEdgeStyle::Square)]
+    pub edge_style: PaperEdgeStyle,
+    /// Slide the app bar out of view when scrolling down, and back in when scrolling up.
+    #[prop_or(false)]
+    pub hide_on_scroll: bool,
+    /// Only elevate the app bar while content is scrolled under it.
+    #[prop_or(false)]
+    pub elevate_on_scroll: bool,
+    /// The scrolling container observed to hide or elevate the app bar.
+    #[prop_or_default]
+    pub scroll_target: ScrollTarget,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
 }
 
 struct DefaultStyles {
@@ -73,15 +124,26 @@ struct DefaultStyles {
     position_relative: Sheet,
     position_static: Sheet,
     // color
-    color_primary: Sheet,
-    color_secondary: Sheet,
+    theme: Theme,
+    color_palette: PaletteSheets<Sheet>,
     color_transparent: Sheet,
     color_inherit: Sheet,
+    // scrolling
+    hide_on_scroll: Sheet,
+    hidden: Sheet,
     // override
     root_override: Sheet,
 }
 
+fn solid_color(background: CssColor, text: CssColor) -> Sheet {
+    sheet!(
+        color: ${text};
+        background-color: ${background};
+    )
+}
+
 fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
+    let z_index = theme.z_index.app_bar;
     let root_style = sheet!(
         display: flex;
         flex-direction: column;
@@ -94,7 +156,7 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         top: 0;
         left: auto;
         right: 0;
-        z-index: 1100; // TODO: put into theme
+        z-index: ${z_index};
         @media print {
             position: absolute;
         }
@@ -104,14 +166,14 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         top: 0;
         left: auto;
         right: 0;
-        z-index: 1100; // TODO: put into theme
+        z-index: ${z_index};
     );
     let position_sticky = sheet!(
         position: sticky;
         top: 0;
         left: auto;
         right: 0;
-        z-index: 1100; // TODO: put into theme
+        z-index: ${z_index};
     );
     let position_static = sheet!(
         position: static;
@@ -119,14 +181,6 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     let position_relative = sheet!(
         position: relative;
     );
-    let color_primary = sheet!(
-        color: ${theme.palette.primary.contrast};
-        background-color: ${theme.palette.primary.main};
-    );
-    let color_secondary = sheet!(
-        color: ${theme.palette.secondary.contrast};
-        background-color: ${theme.palette.secondary.main};
-    );
     let color_inherit = sheet!(
         color: inherit;
     );
@@ -134,7 +188,15 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         color: inherit;
         background-color: transparent;
     );
-    // TODO: implement dark mode
+    let color_palette =
+        PaletteSheets::new(&theme.palette, |spec| solid_color(spec.main, spec.contrast));
+    let hide_on_scroll = theme.motion.transition(
+        "box-shadow 300ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        transform 225ms cubic-bezier(0.4, 0, 0.2, 1) 0ms",
+    );
+    let hidden = sheet!(
+        transform: translateY(-100%);
+    );
 
     let root_override = theme
         .components
@@ -150,16 +212,18 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         position_sticky,
         position_static,
         position_relative,
-        color_primary,
-        color_secondary,
+        theme: theme.clone(),
+        color_palette,
         color_inherit,
         color_transparent,
+        hide_on_scroll,
+        hidden,
         root_override,
     }
 }
 
 impl DefaultStyles {
-    fn build_root_style(&self, props: &AppBarProperties) -> Vec<ScopeContent> {
+    fn build_root_style(&self, props: &AppBarProperties, hidden: bool) -> Vec<ScopeContent> {
         use AppBarColor::*;
         use AppBarPosition::*;
 
@@ -172,12 +236,31 @@ impl DefaultStyles {
             Static => &self.position_static,
             Sticky => &self.position_sticky,
         });
-        collected_scopes.extend_from_slice(match props.color {
-            Primary => &self.color_primary,
-            Secondary => &self.color_secondary,
+        let palette = &self.theme.palette;
+        let keep_paper_colors = palette.mode == PaletteMode::Dark && !props.enable_color_on_dark;
+        let background_scopes;
+        let color_scopes: &[ScopeContent] = match props.color {
             Transparent => &self.color_transparent,
             Inherit => &self.color_inherit,
-        });
+            _ if keep_paper_colors => &[],
+            Background(background) => {
+                background_scopes =
+                    solid_color(background, palette.contrast_text_color(background));
+                &background_scopes
+            }
+            color => color
+                .palette_color()
+                .and_then(|c| self.color_palette.get(c))
+                .map(|sheet| &**sheet)
+                .unwrap_or_default(),
+        };
+        collected_scopes.extend_from_slice(color_scopes);
+        if props.hide_on_scroll {
+            collected_scopes.extend_from_slice(&self.hide_on_scroll);
+        }
+        if hidden {
+            collected_scopes.extend_from_slice(&self.hidden);
+        }
         collected_scopes.extend_from_slice(&self.root_override);
 
         collected_scopes
@@ -187,13 +270,35 @@ impl DefaultStyles {
 #[function_component]
 pub fn AppBar(props: &AppBarProperties) -> Html {
     let styles = use_theme(derive_styles_from_theme);
+    let hide_trigger =
+        use_optional_scroll_trigger(props.hide_on_scroll.then(|| ScrollTriggerOptions {
+            target: props.scroll_target.clone(),
+            ..Default::default()
+        }));
+    let elevate_trigger =
+        use_optional_scroll_trigger(props.elevate_on_scroll.then(|| ScrollTriggerOptions {
+            target: props.scroll_target.clone(),
+            threshold: 0.,
+            disable_hysteresis: true,
+        }));
+    let elevation = if props.elevate_on_scroll && !elevate_trigger {
+        0
+    } else {
+        props.elevation
+    };
 
-    let mut root_style = styles.build_root_style(props);
+    let mut root_style = styles.build_root_style(props, hide_trigger);
     root_style.extend_from_slice(&props.class);
     let root_style = Sheet::from(root_style);
 
     html! {
-        <Paper class={root_style} variant={PaperVariant::Elevated(4)} edge_style={PaperEdgeStyle::Square}>
+        <Paper
+            class={root_style}
+            variant={PaperVariant::Elevated(elevation)}
+            edge_style={props.edge_style}
+            attributes={props.attributes.clone()}
+            node_ref={props.node_ref.clone()}
+        >
             { for props.children.iter() }
         </Paper>
     }
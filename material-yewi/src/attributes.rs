@@ -0,0 +1,173 @@
+//! Passing additional html attributes and event listeners to the root element of a component.
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use yew::virtual_dom::{AttrValue, Listener};
+use yew::Callback;
+use yew::Html;
+use yew::{Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent, TouchEvent, WheelEvent};
+
+/// Additional attributes and event listeners, applied to the root element a component renders.
+///
+/// Use this to set ids, aria attributes, inline styles or data attributes, and to listen to events
+/// the component does not expose itself. A `class` is appended to the classes of the component,
+/// every other attribute replaces the value the component would set.
+///
+/// Two sets compare equal if they have the same attributes and the same callbacks, so keep
+/// callbacks stable across renders, e.g. with `use_state`, to avoid rerendering the component.
+///
+/// ```
+/// # use material_yewi::attributes::ExtraAttributes;
+/// # use yew::{html, Callback, Html};
+/// let extra = ExtraAttributes::new()
+///     .attr("id", "main")
+///     .attr("class", "extra")
+///     .on_mouse_enter(Callback::from(|_| {}));
+/// let root = extra.apply_to(html! { <div class="root" /> });
+/// match root {
+///     Html::VTag(tag) => {
+///         let attributes: Vec<_> = tag.attributes.iter().collect();
+///         assert!(attributes.contains(&("id", "main")));
+///         assert!(attributes.contains(&("class", "root extra")));
+///     }
+///     _ => unreachable!("the root is an element"),
+/// }
+/// ```
+#[derive(Clone, Default, PartialEq)]
+pub struct ExtraAttributes {
+    attributes: Vec<(&'static str, AttrValue)>,
+    listeners: Vec<ExtraListener>,
+}
+
+macro_rules! extra_listeners {
+    ($($method:ident => $variant:ident($event:ty) as $listener:ident;)*) => {
+        #[derive(Clone)]
+        enum ExtraListener {
+            $($variant(Callback<$event>),)*
+            Generic(Rc<dyn Listener>),
+        }
+
+        impl ExtraListener {
+            fn event_name(&self) -> Cow<'static, str> {
+                match self {
+                    $(Self::$variant(_) => stringify!($listener).into(),)*
+                    Self::Generic(listener) => format!("on{}", listener.kind().type_name()).into(),
+                }
+            }
+
+            fn to_listener(&self) -> Rc<dyn Listener> {
+                match self {
+                    $(Self::$variant(callback) => {
+                        Rc::new(yew::html::$listener::Wrapper::new(callback.clone()))
+                    })*
+                    Self::Generic(listener) => listener.clone(),
+                }
+            }
+        }
+
+        impl PartialEq for ExtraListener {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    $((Self::$variant(a), Self::$variant(b)) => a == b,)*
+                    (Self::Generic(a), Self::Generic(b)) => Rc::ptr_eq(a, b),
+                    _ => false,
+                }
+            }
+        }
+
+        impl ExtraAttributes {
+            $(
+                #[doc = concat!("Listen to the `", stringify!($listener), "` event.")]
+                pub fn $method(mut self, callback: Callback<$event>) -> Self {
+                    self.listeners.push(ExtraListener::$variant(callback));
+                    self
+                }
+            )*
+        }
+    };
+}
+
+extra_listeners! {
+    on_click => Click(MouseEvent) as onclick;
+    on_double_click => DoubleClick(MouseEvent) as ondblclick;
+    on_context_menu => ContextMenu(MouseEvent) as oncontextmenu;
+    on_mouse_down => MouseDown(MouseEvent) as onmousedown;
+    on_mouse_up => MouseUp(MouseEvent) as onmouseup;
+    on_mouse_enter => MouseEnter(MouseEvent) as onmouseenter;
+    on_mouse_leave => MouseLeave(MouseEvent) as onmouseleave;
+    on_mouse_move => MouseMove(MouseEvent) as onmousemove;
+    on_mouse_over => MouseOver(MouseEvent) as onmouseover;
+    on_mouse_out => MouseOut(MouseEvent) as onmouseout;
+    on_pointer_down => PointerDown(PointerEvent) as onpointerdown;
+    on_pointer_up => PointerUp(PointerEvent) as onpointerup;
+    on_pointer_enter => PointerEnter(PointerEvent) as onpointerenter;
+    on_pointer_leave => PointerLeave(PointerEvent) as onpointerleave;
+    on_focus => Focus(FocusEvent) as onfocus;
+    on_blur => Blur(FocusEvent) as onblur;
+    on_focus_in => FocusIn(FocusEvent) as onfocusin;
+    on_focus_out => FocusOut(FocusEvent) as onfocusout;
+    on_key_down => KeyDown(KeyboardEvent) as onkeydown;
+    on_key_up => KeyUp(KeyboardEvent) as onkeyup;
+    on_touch_start => TouchStart(TouchEvent) as ontouchstart;
+    on_touch_end => TouchEnd(TouchEvent) as ontouchend;
+    on_touch_move => TouchMove(TouchEvent) as ontouchmove;
+    on_scroll => Scroll(Event) as onscroll;
+    on_wheel => Wheel(WheelEvent) as onwheel;
+}
+
+impl ExtraAttributes {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    /// Add an attribute, replacing a previously added attribute of the same name.
+    pub fn attr(mut self, name: &'static str, value: impl Into<AttrValue>) -> Self {
+        let value = value.into();
+        match self.attributes.iter_mut().find(|(n, _)| *n == name) {
+            Some(attribute) => attribute.1 = value,
+            None => self.attributes.push((name, value)),
+        }
+        self
+    }
+
+    /// Add an event listener for events without a typed helper, for example
+    /// `yew::html::onfocus::Wrapper::new(callback)`. Generic listeners only compare equal to
+    /// clones of the same set of attributes, so build them once and not on every render.
+    pub fn listener(mut self, listener: impl 'static + Listener) -> Self {
+        self.listeners
+            .push(ExtraListener::Generic(Rc::new(listener)));
+        self
+    }
+
+    /// Apply the attributes and listeners to the root element of the given html.
+    /// If the root is not an element, nothing is applied.
+    pub fn apply_to(&self, mut html: Html) -> Html {
+        if let Html::VTag(ref mut tag) = html {
+            for (name, value) in self.attributes.iter() {
+                let existing_class = (*name == "class")
+                    .then(|| tag.attributes.iter().find(|(n, _)| *n == "class"))
+                    .flatten()
+                    .map(|(_, classes)| classes.to_string());
+                match existing_class {
+                    Some(classes) => tag.add_attribute(name, format!("{} {}", classes, value)),
+                    None => tag.add_attribute(name, value.clone()),
+                }
+            }
+            for listener in self.listeners.iter() {
+                tag.add_listener(listener.to_listener());
+            }
+        }
+        html
+    }
+}
+
+impl Debug for ExtraAttributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let listeners: Vec<_> = self.listeners.iter().map(|l| l.event_name()).collect();
+        f.debug_struct("ExtraAttributes")
+            .field("attributes", &self.attributes)
+            .field("listeners", &listeners)
+            .finish()
+    }
+}
@@ -7,6 +7,48 @@ pub fn set_timeout(handler: impl 'static + FnOnce(), delay_millis: u32) -> Timeo
     TimeoutHandle(Timeout::new(delay_millis, handler))
 }
 
+/// Cancel the timeout. Dropping the handle clears it, forgetting the handle would let the
+/// handler run anyway, e.g. after the state it updates has been torn down.
 pub fn clear_timeout(timeout: TimeoutHandle) {
-    std::mem::forget(timeout)
+    drop(timeout)
+}
+
+// ResizeObserver is only available behind `web_sys_unstable_apis`, bind the parts we need.
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    type ResizeObserver;
+    #[wasm_bindgen::prelude::wasm_bindgen(constructor, catch)]
+    fn new(callback: &js_sys::Function) -> Result<ResizeObserver, wasm_bindgen::JsValue>;
+    #[wasm_bindgen::prelude::wasm_bindgen(method)]
+    fn observe(this: &ResizeObserver, target: &web_sys::Element);
+    #[wasm_bindgen::prelude::wasm_bindgen(method)]
+    fn disconnect(this: &ResizeObserver);
+}
+
+/// Observes the size of an element until dropped.
+pub struct ResizeObserverHandle {
+    observer: ResizeObserver,
+    _callback: wasm_bindgen::closure::Closure<dyn FnMut()>,
+}
+
+/// Call the handler whenever the size of the element changes, and once after observing it.
+/// Returns `None` if the browser does not support `ResizeObserver`.
+pub fn observe_resize(
+    element: &web_sys::Element,
+    handler: impl 'static + FnMut(),
+) -> Option<ResizeObserverHandle> {
+    use wasm_bindgen::JsCast;
+    let callback = wasm_bindgen::closure::Closure::wrap(Box::new(handler) as Box<dyn FnMut()>);
+    let observer = ResizeObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+    observer.observe(element);
+    Some(ResizeObserverHandle {
+        observer,
+        _callback: callback,
+    })
+}
+
+impl Drop for ResizeObserverHandle {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
 }
@@ -1,18 +1,27 @@
 use material_styles_yew::use_theme;
+use material_styles_yew::ColorSpec;
 use material_styles_yew::CssColor;
+use material_styles_yew::PaletteColor;
 use material_styles_yew::Theme;
 use stylist::ast::{sheet, ScopeContent, Sheet};
+use stylist::yew::use_style;
+use yew::classes;
 use yew::function_component;
 use yew::html;
+use yew::virtual_dom::AttrValue;
 use yew::Callback;
 use yew::Children;
 use yew::Html;
+use yew::NodeRef;
 use yew::Properties;
 
+use crate::attributes::ExtraAttributes;
 use crate::button_base::ButtonBase;
 pub use crate::button_base::{
-    ButtonPressedEvent, RippleBehaviour, CLASS_DISABLED, CLASS_FOCUS_VISIBLE,
+    ButtonBaseActions, ButtonPressedEvent, ButtonType, ImperativeRef, RippleBehaviour,
+    CLASS_DISABLED, CLASS_FOCUS_VISIBLE,
 };
+use crate::palette_sheets::PaletteSheets;
 // FIXME: ripple effects
 
 #[derive(Debug, Clone, PartialEq)]
@@ -26,13 +35,67 @@ impl From<Sheet> for ButtonStyleRoot {
     }
 }
 
+/// Overrides the style of the span wrapping [`ButtonProperties::start_icon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonStartIconStyle {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for ButtonStartIconStyle {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// Overrides the style of the span wrapping [`ButtonProperties::end_icon`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ButtonEndIconStyle {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for ButtonEndIconStyle {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// The color of a button, shared by [`Button`], [`IconButton`] and [`Fab`].
+///
+/// [`IconButton`]: crate::icon_button::IconButton
+/// [`Fab`]: crate::fab::Fab
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum ButtonColor {
+    /// A neutral color, the default of icon and floating action buttons. Buttons get the primary
+    /// text color of the palette, and a gray background when contained.
+    Default,
     Primary,
     Secondary,
+    Error,
+    Warning,
+    Info,
+    Success,
+    /// A custom color of the palette, by its key in `Palette::custom`
+    Custom(&'static str),
+    /// The color of the parent element. Contained buttons keep the gray background of
+    /// [`ButtonColor::Default`].
     Inherit,
 }
 
+impl ButtonColor {
+    pub(crate) fn palette_color(self) -> Option<PaletteColor> {
+        match self {
+            Self::Primary => Some(PaletteColor::Primary),
+            Self::Secondary => Some(PaletteColor::Secondary),
+            Self::Error => Some(PaletteColor::Error),
+            Self::Warning => Some(PaletteColor::Warning),
+            Self::Info => Some(PaletteColor::Info),
+            Self::Success => Some(PaletteColor::Success),
+            Self::Custom(key) => Some(PaletteColor::Custom(key)),
+            Self::Default | Self::Inherit => None,
+        }
+    }
+}
+
 impl Default for ButtonColor {
     fn default() -> Self {
         ButtonColor::Primary
@@ -71,9 +134,15 @@ pub struct ButtonProperties {
     pub class: Sheet,
     #[prop_or_default]
     pub children: Children,
-    /// Event fired when the button is considered pressed.
-    /// This currently only includes clicking it, but would be expanded to keyboard events Enter and Spacebar
-    ///  for screen reading compatibility. Also works correctly with Touch events on devices without a pointer.
+    /// An icon shown before the children.
+    #[prop_or_default]
+    pub start_icon: Option<Html>,
+    /// An icon shown after the children.
+    #[prop_or_default]
+    pub end_icon: Option<Html>,
+    /// Event fired when the button is pressed: when it is clicked or tapped, when Enter is pressed
+    /// down on it, or when the Spacebar is released on it. Like a native link, a button rendered
+    /// as a link is not pressed by the Spacebar. A disabled button is never pressed.
     #[prop_or_default]
     pub on_pressed: Callback<ButtonPressedEvent>,
     #[prop_or_default]
@@ -84,10 +153,55 @@ pub struct ButtonProperties {
     pub size: ButtonSize,
     #[prop_or(false)]
     pub disabled: bool,
+    /// Take up the full width of the container.
+    #[prop_or(false)]
+    pub full_width: bool,
+    /// Remove the shadows of a contained button.
+    #[prop_or(false)]
+    pub disable_elevation: bool,
     #[prop_or(RippleBehaviour::Interactive)]
     pub ripples: RippleBehaviour,
     #[prop_or(0)]
     pub tab_index: i32,
+    /// Render the button as a link to the given url, see [`ButtonBase`].
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    #[prop_or_default]
+    pub target: Option<AttrValue>,
+    #[prop_or_default]
+    pub rel: Option<AttrValue>,
+    #[prop_or_default]
+    pub download: Option<AttrValue>,
+    /// The tag name of the element to render, see [`ButtonBase`].
+    #[prop_or_default]
+    pub element: Option<AttrValue>,
+    /// The `type` of the native button, defaulting to [`ButtonType::Button`].
+    #[prop_or_default]
+    pub button_type: ButtonType,
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+    #[prop_or(false)]
+    pub autofocus: bool,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+    /// Bound to actions to programmatically focus the button, see [`ButtonBaseActions`].
+    #[prop_or_default]
+    pub action: ImperativeRef<ButtonBaseActions>,
+}
+
+/// The coloring of each variant for one color
+struct ColorSheets {
+    text: Sheet,
+    outlined: Sheet,
+    contained: Sheet,
 }
 
 struct DefaultStyles {
@@ -103,20 +217,26 @@ struct DefaultStyles {
     size_contained_medium: Sheet,
     size_contained_large: Sheet,
     // coloring
-    color_text_inherit: Sheet,
-    color_text_primary: Sheet,
-    color_text_secondary: Sheet,
-    color_outlined_inherit: Sheet,
-    color_outlined_primary: Sheet,
-    color_outlined_secondary: Sheet,
-    color_contained_inherit: Sheet,
-    color_contained_primary: Sheet,
-    color_contained_secondary: Sheet,
+    color_default: ColorSheets,
+    color_inherit: ColorSheets,
+    color_palette: PaletteSheets<ColorSheets>,
     // disabled
     outlined: Sheet,
     contained: Sheet,
+    disable_elevation: Sheet,
+    full_width: Sheet,
+    // icons
+    start_icon: Sheet,
+    start_icon_small: Sheet,
+    end_icon: Sheet,
+    end_icon_small: Sheet,
+    icon_size_small: Sheet,
+    icon_size_medium: Sheet,
+    icon_size_large: Sheet,
     // overrides
     root_override: Sheet,
+    start_icon_override: Sheet,
+    end_icon_override: Sheet,
 }
 
 fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
@@ -135,11 +255,6 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     let root_basebox = sheet!(
         min-width: 64px;
         border-radius: ${theme.shape.border_radius.clone()};
-        transition:
-            background-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms,
-            box-shadow 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms,
-            border-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms,
-            color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms;
         &.${CLASS_DISABLED} {
             color: ${disabled_color};
         }
@@ -147,9 +262,16 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     let root_hover = sheet!(
         &:hover { text-decoration: none; }
     );
+    let root_transition = theme.motion.transition(
+        "background-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        box-shadow 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        border-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms",
+    );
     let mut root_inline = vec![];
     root_inline.extend_from_slice(&theme.typography.button);
     root_inline.extend_from_slice(&root_basebox);
+    root_inline.extend_from_slice(&root_transition);
     root_inline.extend_from_slice(&root_hover);
     let root_inline = Sheet::from(root_inline);
 
@@ -205,126 +327,132 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         }
     );
 
-    let to_hover = |c: CssColor| c.alpha_multiply(theme.palette.actions.hover_opacity);
-    let color_primary = &theme.palette.primary;
-    let color_secondary = &theme.palette.secondary;
-
-    let color_text_inherit = sheet!(
-        color: inherit;
-        &:hover {
-            background-color: ${to_hover(theme.palette.text.primary)};
-        }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
-        }
-    );
-    let color_text_primary = sheet!(
-        color: ${color_primary.main};
-        &:hover {
-            background-color: ${to_hover(color_primary.main)};
-        }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
-        }
-    );
-    let color_text_secondary = sheet!(
-        color: ${color_secondary.main};
-        &:hover {
-            background-color: ${to_hover(color_secondary.main)};
-        }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
-        }
-    );
-
-    let color_outlined_inherit = sheet!(
-        color: inherit;
-        border: 1px solid ${" "}${CssColor::rgba(0, 0, 0, 0.32)};
+    let disable_elevation = sheet!(
+        box-shadow: none;
         &:hover {
-            background-color: ${to_hover(theme.palette.text.primary)};
-        }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
-        }
-    );
-    let color_outlined_primary = sheet!(
-        color: ${color_primary.main};
-        border: 1px solid ${" "}${color_primary.main.alpha_multiply(0.5)};
-        &:hover {
-            border: 1px solid ${" "}${color_primary.main};
-            background-color: ${to_hover(color_primary.main)};
-        }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
+            box-shadow: none;
         }
-    );
-    let color_outlined_secondary = sheet!(
-        color: ${color_secondary.main};
-        border: 1px solid ${" "}${color_secondary.main.alpha_multiply(0.5)};
-        &:hover {
-            border: 1px solid ${" "}${color_secondary.main};
-            background-color: ${to_hover(color_secondary.main)};
+        &.${CLASS_FOCUS_VISIBLE} {
+            box-shadow: none;
         }
-        @media (hover: none) {
-            &:hover { background-color: transparent; }
+        &:active {
+            box-shadow: none;
         }
         &.${CLASS_DISABLED} {
-            border: 1px solid ${" "}${disabled_color};
+            box-shadow: none;
         }
     );
+    let full_width = sheet!(
+        width: 100%;
+    );
 
-    let color_contained_inherit = sheet!(
-        color: ${theme.palette.contrast_text_color(gray_300)};
-        background-color: ${gray_300};
-        box-shadow: ${shadows2};
-        &:hover {
-            background-color: ${gray_a100};
-            box-shadow: ${shadows4};
-        }
-        &:active {
-            box-shadow: ${shadows8};
-        }
-        @media (hover: none) {
+    let to_hover = |c: CssColor| c.alpha_multiply(theme.palette.actions.hover_opacity);
+    let text_color = |color: Option<CssColor>, hover: CssColor| {
+        let color = color.map_or_else(|| "inherit".to_string(), |c| c.to_css_value());
+        sheet!(
+            color: ${color};
             &:hover {
-                background-color: ${gray_300};
-                box-shadow: ${shadows2};
+                background-color: ${to_hover(hover)};
             }
-        }
-    );
-    let color_contained_primary = sheet!(
-        color: ${color_primary.contrast};
-        background-color: ${color_primary.main};
-        box-shadow: ${shadows2};
-        &:hover {
-            background-color: ${color_primary.dark};
-            box-shadow: ${shadows4};
-        }
-        &:active {
-            box-shadow: ${shadows8};
-        }
-        @media (hover: none) {
+            @media (hover: none) {
+                &:hover { background-color: transparent; }
+            }
+        )
+    };
+    let contained_color = |background: CssColor, hover: CssColor, text: &str| {
+        sheet!(
+            color: ${text};
+            background-color: ${background};
+            box-shadow: ${shadows2};
             &:hover {
-                background-color: ${color_primary.main};
-                box-shadow: ${shadows2};
+                background-color: ${hover};
+                box-shadow: ${shadows4};
             }
-        }
+            &:active {
+                box-shadow: ${shadows8};
+            }
+            @media (hover: none) {
+                &:hover {
+                    background-color: ${background};
+                    box-shadow: ${shadows2};
+                }
+            }
+        )
+    };
+    let palette_color_sheets = |spec: &ColorSpec| ColorSheets {
+        text: text_color(Some(spec.main), spec.main),
+        outlined: sheet!(
+            color: ${spec.main};
+            border: 1px solid ${" "}${spec.main.alpha_multiply(0.5)};
+            &:hover {
+                border: 1px solid ${" "}${spec.main};
+                background-color: ${to_hover(spec.main)};
+            }
+            @media (hover: none) {
+                &:hover { background-color: transparent; }
+            }
+        ),
+        contained: contained_color(spec.main, spec.dark, &spec.contrast.to_css_value()),
+    };
+
+    let neutral_outlined = |color: &str, border: &str| {
+        sheet!(
+            color: ${color};
+            border: 1px solid ${" "}${border};
+            &:hover {
+                background-color: ${to_hover(theme.palette.text.primary)};
+            }
+            @media (hover: none) {
+                &:hover { background-color: transparent; }
+            }
+        )
+    };
+    let text_primary = theme.palette.text.primary.to_css_value();
+    let color_default = ColorSheets {
+        text: text_color(Some(theme.palette.text.primary), theme.palette.text.primary),
+        outlined: neutral_outlined(&text_primary, &CssColor::rgba(0, 0, 0, 0.23).to_css_value()),
+        contained: contained_color(
+            gray_300,
+            gray_a100,
+            &theme.palette.contrast_text_color(gray_300).to_css_value(),
+        ),
+    };
+    let color_inherit = ColorSheets {
+        text: text_color(None, theme.palette.text.primary),
+        outlined: neutral_outlined("inherit", "currentColor"),
+        contained: contained_color(gray_300, gray_a100, "inherit"),
+    };
+    let color_palette = PaletteSheets::new(&theme.palette, palette_color_sheets);
+
+    let start_icon = sheet!(
+        display: inherit;
+        margin-right: 8px;
+        margin-left: -4px;
     );
-    let color_contained_secondary = sheet!(
-        color: ${color_secondary.contrast};
-        background-color: ${color_secondary.main};
-        box-shadow: ${shadows2};
-        &:hover {
-            background-color: ${color_secondary.dark};
-            box-shadow: ${shadows4};
+    let start_icon_small = sheet!(
+        margin-left: -2px;
+    );
+    let end_icon = sheet!(
+        display: inherit;
+        margin-right: -4px;
+        margin-left: 8px;
+    );
+    let end_icon_small = sheet!(
+        margin-right: -2px;
+    );
+    let icon_size_small = sheet!(
+        & > *:nth-of-type(1) {
+            font-size: 18px;
         }
-        &:active {
-            box-shadow: ${shadows8};
+    );
+    let icon_size_medium = sheet!(
+        & > *:nth-of-type(1) {
+            font-size: 20px;
         }
-        @media (hover: none) {
-            &:hover {
-                background-color: ${color_secondary.main};
-                box-shadow: ${shadows2};
-            }
+    );
+    let icon_size_large = sheet!(
+        & > *:nth-of-type(1) {
+            font-size: 22px;
         }
     );
 
@@ -334,6 +462,18 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         .map(|c| &c.css_scopes)
         .cloned()
         .unwrap_or_default();
+    let start_icon_override = theme
+        .components
+        .search_override::<ButtonStartIconStyle>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
+    let end_icon_override = theme
+        .components
+        .search_override::<ButtonEndIconStyle>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
 
     DefaultStyles {
         root_inline,
@@ -348,26 +488,31 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         size_contained_medium,
         size_contained_large,
         //
-        color_text_inherit,
-        color_text_primary,
-        color_text_secondary,
-        color_outlined_inherit,
-        color_outlined_primary,
-        color_outlined_secondary,
-        color_contained_inherit,
-        color_contained_primary,
-        color_contained_secondary,
+        color_default,
+        color_inherit,
+        color_palette,
         //
         outlined,
         contained,
+        disable_elevation,
+        full_width,
+        //
+        start_icon,
+        start_icon_small,
+        end_icon,
+        end_icon_small,
+        icon_size_small,
+        icon_size_medium,
+        icon_size_large,
         //
         root_override,
+        start_icon_override,
+        end_icon_override,
     }
 }
 
 impl DefaultStyles {
     fn build_root_style(&self, props: &ButtonProperties) -> Vec<ScopeContent> {
-        use ButtonColor::*;
         use ButtonSize::*;
         use ButtonVariant::*;
 
@@ -384,26 +529,76 @@ impl DefaultStyles {
             (Contained, Medium) => &self.size_contained_medium,
             (Contained, Large) => &self.size_contained_large,
         });
-        collected_scopes.extend_from_slice(match (props.variant, props.color) {
-            (Text, Inherit) => &self.color_text_inherit,
-            (Text, Primary) => &self.color_text_primary,
-            (Text, Secondary) => &self.color_text_secondary,
-            (Outlined, Inherit) => &self.color_outlined_inherit,
-            (Outlined, Primary) => &self.color_outlined_primary,
-            (Outlined, Secondary) => &self.color_outlined_secondary,
-            (Contained, Inherit) => &self.color_contained_inherit,
-            (Contained, Primary) => &self.color_contained_primary,
-            (Contained, Secondary) => &self.color_contained_secondary,
-        });
-        collected_scopes.extend_from_slice(match (props.variant, props.color) {
-            (Contained, _) => &self.contained,
-            (Outlined, _) => &self.outlined,
+        if let Some(colors) = self.color_sheets(props.color) {
+            collected_scopes.extend_from_slice(match props.variant {
+                Text => &colors.text,
+                Outlined => &colors.outlined,
+                Contained => &colors.contained,
+            });
+        }
+        collected_scopes.extend_from_slice(match props.variant {
+            Contained => &self.contained,
+            Outlined => &self.outlined,
             _ => Default::default(),
         });
+        if props.disable_elevation {
+            collected_scopes.extend_from_slice(&self.disable_elevation);
+        }
+        if props.full_width {
+            collected_scopes.extend_from_slice(&self.full_width);
+        }
         collected_scopes.extend_from_slice(&self.root_override);
 
         collected_scopes
     }
+
+    /// The sheets of a color, or `None` for a custom color missing from the palette
+    fn color_sheets(&self, color: ButtonColor) -> Option<&ColorSheets> {
+        match color {
+            ButtonColor::Default => Some(&self.color_default),
+            ButtonColor::Inherit => Some(&self.color_inherit),
+            color => self.color_palette.get(color.palette_color()?),
+        }
+    }
+
+    fn build_icon_style(&self, props: &ButtonProperties, is_start: bool) -> Sheet {
+        use ButtonSize::*;
+
+        let mut collected_scopes = vec![];
+        collected_scopes.extend_from_slice(match is_start {
+            true => &self.start_icon,
+            false => &self.end_icon,
+        });
+        collected_scopes.extend_from_slice(match (props.size, is_start) {
+            (Small, true) => &self.start_icon_small,
+            (Small, false) => &self.end_icon_small,
+            _ => Default::default(),
+        });
+        collected_scopes.extend_from_slice(match props.size {
+            Small => &self.icon_size_small,
+            Medium => &self.icon_size_medium,
+            Large => &self.icon_size_large,
+        });
+        collected_scopes.extend_from_slice(match is_start {
+            true => &self.start_icon_override,
+            false => &self.end_icon_override,
+        });
+
+        Sheet::from(collected_scopes)
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct IconSlotProps {
+    class: Sheet,
+    icon: Html,
+}
+
+/// The span wrapping an icon of the button, only mounting its style when the icon is set.
+#[function_component]
+fn IconSlot(props: &IconSlotProps) -> Html {
+    let class = use_style(/* "Mwi-button-icon", */ props.class.clone());
+    html! { <span class={classes![class]}>{ props.icon.clone() }</span> }
 }
 
 #[function_component]
@@ -414,6 +609,13 @@ pub fn Button(props: &ButtonProperties) -> Html {
     root_style.extend_from_slice(&props.class);
     let root_style = Sheet::from(root_style);
 
+    let start_icon = props.start_icon.clone().map(|icon| {
+        html! { <IconSlot class={styles.build_icon_style(props, true)} {icon} /> }
+    });
+    let end_icon = props.end_icon.clone().map(|icon| {
+        html! { <IconSlot class={styles.build_icon_style(props, false)} {icon} /> }
+    });
+
     html! {
         <ButtonBase
             class={root_style}
@@ -421,8 +623,23 @@ pub fn Button(props: &ButtonProperties) -> Html {
             on_pressed={props.on_pressed.clone()}
             ripples={props.ripples}
             tab_index={props.tab_index}
+            href={props.href.clone()}
+            target={props.target.clone()}
+            rel={props.rel.clone()}
+            download={props.download.clone()}
+            element={props.element.clone()}
+            button_type={props.button_type}
+            name={props.name.clone()}
+            value={props.value.clone()}
+            form={props.form.clone()}
+            autofocus={props.autofocus}
+            attributes={props.attributes.clone()}
+            node_ref={props.node_ref.clone()}
+            action={props.action.clone()}
         >
+            { for start_icon }
             { for props.children.iter() }
+            { for end_icon }
         </ButtonBase>
     }
 }
@@ -1,27 +1,34 @@
 use material_styles_yew::use_theme;
 use material_styles_yew::Theme;
+use std::borrow::Cow;
 use stylist::ast::{sheet, Sheet};
 use stylist::yew::use_style;
-use web_sys::FocusEvent;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Element, Event, FocusEvent, HtmlAnchorElement, HtmlButtonElement, HtmlElement, Node,
+};
 use yew::classes;
 use yew::function_component;
 use yew::html;
 use yew::use_effect_with_deps;
-use yew::use_node_ref;
+use yew::use_mut_ref;
 use yew::use_state;
+use yew::virtual_dom::AttrValue;
 use yew::Callback;
 use yew::Children;
 use yew::Html;
 use yew::KeyboardEvent;
 use yew::MouseEvent;
+use yew::NodeRef;
 use yew::Properties;
 use yew::TouchEvent;
 
-use super::ripples::*;
-use crate::utils::imperative_ref::ImperativeRef;
-use crate::utils::use_focus_visible::use_focus_visible;
-use crate::utils::use_focus_visible::FocusVisibleHandle;
-use crate::utils::use_state_ext::UseStateHandleExt;
+use crate::attributes::ExtraAttributes;
+use crate::hooks::use_focus_visible;
+use crate::hooks::FocusVisibleHandle;
+use crate::hooks::UseStateHandleExt;
+use crate::hooks::{bind_imperative_ref, ImperativeRef};
+use crate::touch_ripple::{RippleStartOptions, TouchRipple, TouchRippleHandle};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ButtonBaseStyleRoot {
@@ -44,20 +51,80 @@ pub enum ButtonPressedEvent {
     // ... more later on?
 }
 
+/// The behaviour of a native button inside a form.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ButtonType {
+    /// Does nothing on its own. Unlike in html, this is the default.
+    Button,
+    /// Submits the form the button belongs to.
+    Submit,
+    /// Resets all controls of the form the button belongs to.
+    Reset,
+}
+
+impl Default for ButtonType {
+    fn default() -> Self {
+        Self::Button
+    }
+}
+
+impl ButtonType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Button => "button",
+            Self::Submit => "submit",
+            Self::Reset => "reset",
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum RippleBehaviour {
+    /// No ripples are shown, and no ripple elements are rendered
     Disabled,
+    /// Ripples always start from the center of the button
     Centered,
+    /// Ripples start from the position the button was pressed at
     Interactive,
 }
 
+/// Imperative actions on a rendered [`ButtonBase`], see [`ButtonBaseProperties::action`].
+///
+/// Clone the actions out of the [`ImperativeRef`] before invoking them, since the button
+/// might rerender and rebind them in response.
+#[derive(Clone)]
+pub struct ButtonBaseActions {
+    node: NodeRef,
+    show_focus_visible: Callback<()>,
+}
+
+impl ButtonBaseActions {
+    /// Focus the button.
+    pub fn focus(&self) {
+        if let Some(element) = self.node.cast::<HtmlElement>() {
+            let _ = element.focus();
+        }
+    }
+    /// Focus the button and show the focus ring, as if it was focused with the keyboard.
+    pub fn focus_visible(&self) {
+        self.focus();
+        self.show_focus_visible.emit(());
+    }
+    /// Remove focus from the button.
+    pub fn blur(&self) {
+        if let Some(element) = self.node.cast::<HtmlElement>() {
+            let _ = element.blur();
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Properties)]
 pub struct ButtonBaseProperties {
     #[prop_or_default]
     pub class: Sheet,
-    /// Event fired when the button is considered pressed.
-    /// This currently only includes clicking it, but would be expanded to keyboard events Enter and Spacebar
-    ///  for screen reading compatibility. Also works correctly with Touch events on devices without a pointer.
+    /// Event fired when the button is pressed: when it is clicked or tapped, when Enter is pressed
+    /// down on it, or when the Spacebar is released on it. Like a native link, a button rendered
+    /// as a link is not pressed by the Spacebar. A disabled button is never pressed.
     #[prop_or_default]
     pub on_pressed: Callback<ButtonPressedEvent>,
     #[prop_or(false)]
@@ -66,6 +133,48 @@ pub struct ButtonBaseProperties {
     pub ripples: RippleBehaviour,
     #[prop_or(0)]
     pub tab_index: i32,
+    /// Render the button as a link to the given url. Unless a different `element` is
+    /// given, this renders an `<a>` instead of a `<button>`.
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// The browsing context to open `href` in, e.g. `_blank`.
+    #[prop_or_default]
+    pub target: Option<AttrValue>,
+    /// The relationship of the linked resource, e.g. `noopener`.
+    #[prop_or_default]
+    pub rel: Option<AttrValue>,
+    /// Download the linked resource instead of navigating to it, optionally suggesting a filename.
+    #[prop_or_default]
+    pub download: Option<AttrValue>,
+    /// The tag name of the element to render. Elements other than `<button>` and `<a href>`
+    /// get the `button` role and keyboard activation.
+    #[prop_or_default]
+    pub element: Option<AttrValue>,
+    /// The `type` of a native button. Defaults to [`ButtonType::Button`], so that buttons
+    /// inside a form do not submit it unless asked to.
+    #[prop_or_default]
+    pub button_type: ButtonType,
+    /// The name under which `value` is submitted with the form, for native buttons.
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// The value submitted with the form, for native buttons.
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    /// The id of the form a native button belongs to, if it is not its ancestor.
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+    #[prop_or(false)]
+    pub autofocus: bool,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the rendered element. The ref is shared with internal uses, such as focus tracking,
+    /// which follow the bound element and keep their state when a different ref is passed.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+    /// Bound to actions to programmatically focus the button, e.g. in dialogs.
+    #[prop_or_default]
+    pub action: ImperativeRef<ButtonBaseActions>,
     // TODO: additional properties
     #[prop_or_default]
     pub children: Children,
@@ -128,28 +237,61 @@ fn derive_styles_from_theme(theme: &Theme) -> ThemeStyles {
 }
 
 trait RippleHandleAction<Arg> {
-    fn handle(h: &RipplesHandle, arg: Arg);
+    fn handle(h: &TouchRippleHandle, arg: Arg);
 }
 struct RippleActionStart;
 impl RippleHandleAction<MouseEvent> for RippleActionStart {
-    fn handle(h: &RipplesHandle, ev: MouseEvent) {
-        h.start.emit(RippleStartReason::MousePress(ev))
+    fn handle(h: &TouchRippleHandle, ev: MouseEvent) {
+        h.start(ev, RippleStartOptions::default())
     }
 }
 impl RippleHandleAction<TouchEvent> for RippleActionStart {
-    fn handle(h: &RipplesHandle, ev: TouchEvent) {
-        h.start.emit(RippleStartReason::TouchStart(ev))
+    fn handle(h: &TouchRippleHandle, ev: TouchEvent) {
+        h.start(ev, RippleStartOptions::default())
     }
 }
 struct RippleActionStop;
 impl<Arg> RippleHandleAction<Arg> for RippleActionStop {
-    fn handle(h: &RipplesHandle, _: Arg) {
-        h.stop.emit(());
+    fn handle(h: &TouchRippleHandle, _: Arg) {
+        h.stop();
+    }
+}
+struct RippleActionEndTouch;
+impl RippleHandleAction<TouchEvent> for RippleActionEndTouch {
+    fn handle(h: &TouchRippleHandle, _: TouchEvent) {
+        h.end_touch();
     }
 }
 
+/// The kind of element the button is rendered as, determining how the browser activates it.
+#[derive(Copy, Clone, PartialEq)]
+enum HostKind {
+    /// A `<button>` is activated by both Enter and Spacebar
+    Button,
+    /// A `<a href>` is activated by Enter
+    Link,
+    /// Any other element is not activated by the browser
+    Other,
+}
+
+fn host_kind(node: &NodeRef) -> HostKind {
+    match node.cast::<Element>() {
+        Some(e) if e.is_instance_of::<HtmlButtonElement>() => HostKind::Button,
+        Some(e) if e.is_instance_of::<HtmlAnchorElement>() && e.has_attribute("href") => {
+            HostKind::Link
+        }
+        _ => HostKind::Other,
+    }
+}
+
+/// Check if the event was fired on the node itself, not bubbled up from one of its children.
+fn is_event_on(ev: &Event, node: &NodeRef) -> bool {
+    let target = ev.target().and_then(|t| t.dyn_into::<Node>().ok());
+    matches!((target, node.get()), (Some(t), Some(n)) if t.is_same_node(Some(&n)))
+}
+
 fn link_handle<Arg: 'static, Action: RippleHandleAction<Arg>>(
-    handle: &ImperativeRef<RipplesHandle>,
+    handle: &ImperativeRef<TouchRippleHandle>,
     _action: Action,
     event_callback: impl 'static + Fn(&Arg),
 ) -> Callback<Arg> {
@@ -172,8 +314,9 @@ pub fn ButtonBase(props: &ButtonBaseProperties) -> Html {
     root_sheet.extend_from_slice(&props.class);
     let root_style = use_style(/* "Mwi-button-base" */ Sheet::from(root_sheet));
 
-    let button_ref = use_node_ref();
-    let ripples_handle = use_state(ImperativeRef::<RipplesHandle>::new);
+    // Callers often pass a new ref on every render, internal uses must not depend on its identity
+    let button_ref = props.node_ref.clone();
+    let ripples_handle = use_state(ImperativeRef::<TouchRippleHandle>::new);
 
     let FocusVisibleHandle {
         // This focus_visible provides tracking, but is not our source of truth
@@ -191,8 +334,8 @@ pub fn ButtonBase(props: &ButtonBaseProperties) -> Html {
     use_effect_with_deps(
         move |focus_visible| {
             if *focus_visible {
-                if let Some(h) = ripples_handle_capture.get().as_deref() {
-                    h.start.emit(RippleStartReason::FocusVisible);
+                if let Some(h) = ripples_handle_capture.get().as_deref().cloned() {
+                    h.pulsate();
                 }
             }
             || {}
@@ -200,7 +343,110 @@ pub fn ButtonBase(props: &ButtonBaseProperties) -> Html {
         *focus_visible,
     );
 
-    let onclick = props.on_pressed.reform(ButtonPressedEvent::MousePress);
+    let focus_visible_capture = focus_visible.clone();
+    let disabled = props.disabled;
+    bind_imperative_ref(
+        &props.action,
+        ButtonBaseActions {
+            node: button_ref.clone(),
+            show_focus_visible: Callback::from(move |_| {
+                if !disabled {
+                    focus_visible_capture.set(true);
+                }
+            }),
+        },
+    );
+
+    // Keyboard activation follows native buttons: Enter presses on keydown, Spacebar on keyup.
+    // Native buttons and links synthesize a click, which is then reported as the key press.
+    let pending_key_press = use_mut_ref(|| Option::<ButtonPressedEvent>::None);
+    let space_held = use_mut_ref(|| false);
+
+    let pending_key_press_capture = pending_key_press.clone();
+    let on_pressed = props.on_pressed.clone();
+    let disabled = props.disabled;
+    let onclick = Callback::from(move |ev: MouseEvent| {
+        let key_press = pending_key_press_capture.borrow_mut().take();
+        if disabled {
+            // A disabled link has no native disabled state, keep it from navigating
+            ev.prevent_default();
+            return;
+        }
+        match key_press {
+            // clicks synthesized for keyboard activation carry no click count
+            Some(key_press) if ev.detail() == 0 => on_pressed.emit(key_press),
+            _ => on_pressed.emit(ButtonPressedEvent::MousePress(ev)),
+        }
+    });
+
+    let onkeydown = {
+        let ripples_handle = ripples_handle.clone();
+        let focus_visible = focus_visible.clone();
+        let space_held = space_held.clone();
+        let pending_key_press = pending_key_press.clone();
+        let button_ref = button_ref.clone();
+        let on_pressed = props.on_pressed.clone();
+        let disabled = props.disabled;
+        Callback::from(move |ev: KeyboardEvent| {
+            let key = ev.key();
+            if key == " " && *focus_visible && !space_held.replace(true) {
+                // Show a ripple for as long as the key is held down
+                if let Some(h) = ripples_handle.get().as_deref().cloned() {
+                    h.stop();
+                    h.start(ev.clone(), RippleStartOptions::default());
+                }
+            }
+            if !is_event_on(&ev, &button_ref) {
+                return;
+            }
+            let host = host_kind(&button_ref);
+            match key.as_str() {
+                // A disabled link can still be focused, e.g. programmatically
+                "Enter" if disabled && host == HostKind::Link => ev.prevent_default(),
+                _ if disabled => {}
+                // Prevent the page from scrolling
+                " " if host == HostKind::Other => ev.prevent_default(),
+                "Enter" if host != HostKind::Other => {
+                    *pending_key_press.borrow_mut() = Some(ButtonPressedEvent::EnterPress(ev));
+                }
+                "Enter" => {
+                    ev.prevent_default();
+                    on_pressed.emit(ButtonPressedEvent::EnterPress(ev));
+                }
+                _ => {}
+            }
+        })
+    };
+    let onkeyup = {
+        let ripples_handle = ripples_handle.clone();
+        let focus_visible = focus_visible.clone();
+        let space_held = space_held.clone();
+        let pending_key_press = pending_key_press.clone();
+        let button_ref = button_ref.clone();
+        let on_pressed = props.on_pressed.clone();
+        let disabled = props.disabled;
+        Callback::from(move |ev: KeyboardEvent| {
+            if ev.key() != " " {
+                return;
+            }
+            if space_held.replace(false) && *focus_visible && !ev.default_prevented() {
+                if let Some(h) = ripples_handle.get().as_deref().cloned() {
+                    h.stop();
+                    h.pulsate();
+                }
+            }
+            if disabled || ev.default_prevented() || !is_event_on(&ev, &button_ref) {
+                return;
+            }
+            let key_press = ButtonPressedEvent::SpacebarPress(ev);
+            match host_kind(&button_ref) {
+                HostKind::Button => *pending_key_press.borrow_mut() = Some(key_press),
+                // links are not activated by the Spacebar
+                HostKind::Link => {}
+                HostKind::Other => on_pressed.emit(key_press),
+            }
+        })
+    };
 
     let onmousedown = link_handle(&ripples_handle, RippleActionStart, |_| {});
     let ontouchstart = link_handle(&ripples_handle, RippleActionStart, |_| {});
@@ -215,12 +461,14 @@ pub fn ButtonBase(props: &ButtonBaseProperties) -> Html {
             ev.prevent_default();
         }
     });
-    let ontouchend = link_handle(&ripples_handle, RippleActionStop, |_| {});
+    let ontouchend = link_handle(&ripples_handle, RippleActionEndTouch, |_| {});
     let ontouchmove = link_handle(&ripples_handle, RippleActionStop, |_| {});
+    let ontouchcancel = link_handle(&ripples_handle, RippleActionStop, |_| {});
 
     let focus_visible_capture = focus_visible.clone();
     let tracked_focus_visible_capture = tracked_focus_visible.clone();
     let onfocusout = link_handle(&ripples_handle, RippleActionStop, move |ev: &FocusEvent| {
+        space_held.replace(false);
         handle_blur_visible.emit(ev.clone());
         focus_visible_capture.relaxed_set(*tracked_focus_visible_capture.borrow());
     });
@@ -234,36 +482,80 @@ pub fn ButtonBase(props: &ButtonBaseProperties) -> Html {
         })
     }))
     .clone();
-    // TODO: onkeydown
-    // TODO: onkeyup
 
     let classes = classes![
         root_style,
         props.disabled.then(|| CLASS_DISABLED),
         focus_visible.then(|| CLASS_FOCUS_VISIBLE),
     ];
-    let ripples_handle = (*ripples_handle).clone();
-    html! {
-        <button
-            ref={&button_ref}
-            class={classes}
-            {onfocusout}
-            {onclick}
-            {oncontextmenu}
-            {onfocusin}
-            // {onkeydown}
-            // {onkeyup}
-            {onmousedown}
-            {onmouseout}
-            {onmouseup}
-            // {ondragleave}
-            {ontouchend}
-            {ontouchmove}
-            {ontouchstart}
-            tabindex={(if props.disabled { -1 } else { props.tab_index }).to_string()}
-        >
-            { for props.children.iter() }
-            <Ripples handle={ripples_handle} />
-        </button>
+    let element: Cow<'static, str> = match props.element {
+        Some(ref element) => element.to_string().into(),
+        None if props.href.is_some() => "a".into(),
+        None => "button".into(),
+    };
+    let is_native_button = element == "button";
+    let is_link = element == "a" && props.href.is_some();
+    let role = (!is_native_button && !is_link).then(|| "button");
+    let aria_disabled = (!is_native_button && props.disabled).then(|| "true");
+    let button_type = is_native_button.then(|| props.button_type.as_str());
+    let name = props.name.clone().filter(|_| is_native_button);
+    let form = props.form.clone().filter(|_| is_native_button);
+    let value = props.value.clone().filter(|_| is_native_button);
+
+    let ripples = match props.ripples {
+        RippleBehaviour::Disabled => html! {},
+        behaviour => html! {
+            <TouchRipple
+                handle={(*ripples_handle).clone()}
+                center={behaviour == RippleBehaviour::Centered}
+            />
+        },
+    };
+    // html! can not leave out the value of a dynamic tag, so only native buttons with a value
+    // render one
+    macro_rules! host {
+        ($($value:tt)*) => {
+            html! {
+                <@{element}
+                    ref={&button_ref}
+                    class={classes}
+                    href={props.href.clone()}
+                    target={props.target.clone()}
+                    rel={props.rel.clone()}
+                    download={props.download.clone()}
+                    disabled={is_native_button && props.disabled}
+                    {role}
+                    aria-disabled={aria_disabled}
+                    type={button_type}
+                    {name}
+                    $($value)*
+                    {form}
+                    autofocus={props.autofocus}
+                    {onfocusout}
+                    {onclick}
+                    {oncontextmenu}
+                    {onfocusin}
+                    {onkeydown}
+                    {onkeyup}
+                    {onmousedown}
+                    {onmouseout}
+                    {onmouseup}
+                    // {ondragleave}
+                    {ontouchcancel}
+                    {ontouchend}
+                    {ontouchmove}
+                    {ontouchstart}
+                    tabindex={(if props.disabled { -1 } else { props.tab_index }).to_string()}
+                >
+                    { for props.children.iter() }
+                    {ripples}
+                </@>
+            }
+        };
     }
+    let button = match value {
+        Some(value) => host!(value = { value }),
+        None => host!(),
+    };
+    props.attributes.apply_to(button)
 }
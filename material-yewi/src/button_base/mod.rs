@@ -1,7 +1,8 @@
 mod base;
-mod ripple;
-mod ripples;
 
+pub use crate::hooks::ImperativeRef;
+pub use crate::touch_ripple::RippleConfig;
 pub use base::{
-    ButtonBase, ButtonPressedEvent, RippleBehaviour, CLASS_DISABLED, CLASS_FOCUS_VISIBLE,
+    ButtonBase, ButtonBaseActions, ButtonBaseProperties, ButtonPressedEvent, ButtonType,
+    RippleBehaviour, CLASS_DISABLED, CLASS_FOCUS_VISIBLE,
 };
@@ -1,11 +1,13 @@
+use crate::attributes::ExtraAttributes;
 use crate::button::{Button, ButtonColor, ButtonProperties, ButtonVariant, CLASS_DISABLED};
-use material_styles_yew::{use_theme, CssColor, Theme};
+use crate::palette_sheets::PaletteSheets;
+use material_styles_yew::{use_theme, CssColor, PaletteMode, Theme};
 use stylist::{
     ast::{sheet, ScopeContent, Sheet},
     yew::use_style,
 };
 use yew::Html;
-use yew::{classes, function_component, html, html_nested, ChildrenWithProps, Properties};
+use yew::{classes, function_component, html, html_nested, ChildrenWithProps, NodeRef, Properties};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ButtonGroupStyleRoot {
@@ -38,6 +40,12 @@ pub struct ButtonGroupProperties {
     pub children: ChildrenWithProps<Button>,
     #[prop_or_default]
     pub orientation: Orientation,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
 }
 
 struct DefaultStyles {
@@ -47,16 +55,15 @@ struct DefaultStyles {
     button: Sheet,
     button_horizontal: Sheet,
     button_vertical: Sheet,
+    // colored dividers, for the text and contained variants
+    divider_default: (Sheet, Sheet),
+    divider_palette: PaletteSheets<(Sheet, Sheet)>,
     // variant::text
     button_horizontal_text: Sheet,
     button_vertical_text: Sheet,
-    button_text_color_primary: Sheet,
-    button_text_color_secondary: Sheet,
     // variant::contained
     button_horizontal_contained: Sheet,
     button_vertical_contained: Sheet,
-    button_contained_color_primary: Sheet,
-    button_contained_color_secondary: Sheet,
     // variant::outlined
     button_horizontal_outlined: Sheet,
     button_vertical_outlined: Sheet,
@@ -110,16 +117,6 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
             border-bottom: 1px solid rgba(255, 255, 255, 0.23);
         }
     );
-    let button_text_color_primary = sheet!(
-        &:not(:last-of-type) {
-            border-color: ${theme.palette.primary.main.alpha_multiply(0.5)};
-        }
-    );
-    let button_text_color_secondary = sheet!(
-        &:not(:last-of-type) {
-            border-color: ${theme.palette.secondary.main.alpha_multiply(0.5)};
-        }
-    );
     let button_horizontal_contained = sheet!(
         &:not(:last-of-type) {
             border-right: 1px solid${" "}${gray_400};
@@ -136,16 +133,6 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
             border-bottom: 1px solid${" "}${theme.palette.actions.disabled};
         }
     );
-    let button_contained_color_primary = sheet!(
-        &:not(:last-of-type) {
-            border-color: ${theme.palette.primary.dark};
-        }
-    );
-    let button_contained_color_secondary = sheet!(
-        &:not(:last-of-type) {
-            border-color: ${theme.palette.secondary.dark};
-        }
-    );
     let button_horizontal_outlined = sheet!(
         &:not(:first-of-type) {
             margin-left: -1px;
@@ -168,6 +155,21 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
             border-bottom-color: currentColor;
         }
     );
+    let divider = |color: CssColor| {
+        sheet!(
+            &:not(:last-of-type) {
+                border-color: ${color};
+            }
+        )
+    };
+    let neutral_divider = match theme.palette.mode {
+        PaletteMode::Light => CssColor::rgba(0, 0, 0, 0.23),
+        PaletteMode::Dark => CssColor::rgba(255, 255, 255, 0.23),
+    };
+    let divider_default = (divider(neutral_divider), divider(gray_400));
+    let divider_palette = PaletteSheets::new(&theme.palette, |spec| {
+        (divider(spec.main.alpha_multiply(0.5)), divider(spec.dark))
+    });
 
     let root_override = theme
         .components
@@ -182,12 +184,10 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         button,
         button_horizontal,
         button_vertical,
-        button_text_color_primary,
-        button_text_color_secondary,
+        divider_default,
+        divider_palette,
         button_horizontal_text,
         button_vertical_text,
-        button_contained_color_primary,
-        button_contained_color_secondary,
         button_horizontal_contained,
         button_vertical_contained,
         button_horizontal_outlined,
@@ -215,7 +215,6 @@ impl DefaultStyles {
         group_props: &ButtonGroupProperties,
         button_props: &ButtonProperties,
     ) -> Vec<ScopeContent> {
-        use ButtonColor::*;
         use ButtonVariant::*;
         use Orientation::*;
 
@@ -233,14 +232,18 @@ impl DefaultStyles {
             (Horizontal, Outlined) => &self.button_horizontal_outlined,
             (Vertical, Outlined) => &self.button_vertical_outlined,
         });
-        collected_scopes.extend_from_slice(match (button_props.variant, button_props.color) {
-            (Text, Primary) => &self.button_text_color_primary,
-            (Text, Secondary) => &self.button_text_color_secondary,
-            (Contained, Primary) => &self.button_contained_color_primary,
-            (Contained, Secondary) => &self.button_contained_color_secondary,
-            (Outlined, Primary) => &[],
-            (Outlined, Secondary) => &[],
-            (_, Inherit) => &[],
+        let dividers = match button_props.color {
+            ButtonColor::Default => Some(&self.divider_default),
+            // inherited colors keep the dividers of the variant
+            ButtonColor::Inherit => None,
+            color => color
+                .palette_color()
+                .and_then(|c| self.divider_palette.get(c)),
+        };
+        collected_scopes.extend_from_slice(match (button_props.variant, dividers) {
+            (Text, Some((text, _))) => text,
+            (Contained, Some((_, contained))) => contained,
+            (Outlined, _) | (_, None) => &[],
         });
         collected_scopes
     }
@@ -255,8 +258,8 @@ pub fn ButtonGroup(props: &ButtonGroupProperties) -> Html {
     let root_style = Sheet::from(root_style);
     let root_style = use_style(/* "Mwi-button-group", */ root_style);
 
-    html! {
-        <div class={classes![root_style]}>
+    props.attributes.apply_to(html! {
+        <div ref={&props.node_ref} class={classes![root_style]}>
             {
                 for props.children.iter().map(|button| {
                     let mut button_style = styles.build_button_style(props, button.props.as_ref());
@@ -268,5 +271,5 @@ pub fn ButtonGroup(props: &ButtonGroupProperties) -> Html {
                 })
             }
         </div>
-    }
+    })
 }
@@ -0,0 +1,322 @@
+use material_styles_yew::use_theme;
+use material_styles_yew::CssColor;
+use material_styles_yew::Theme;
+use stylist::ast::{sheet, ScopeContent, Sheet};
+use yew::function_component;
+use yew::html;
+use yew::virtual_dom::AttrValue;
+use yew::Callback;
+use yew::Children;
+use yew::Html;
+use yew::NodeRef;
+use yew::Properties;
+
+use crate::attributes::ExtraAttributes;
+use crate::button::{ButtonColor, ButtonSize};
+use crate::button_base::{
+    ButtonBase, ButtonBaseActions, ButtonPressedEvent, ButtonType, ImperativeRef, RippleBehaviour,
+    CLASS_DISABLED, CLASS_FOCUS_VISIBLE,
+};
+use crate::palette_sheets::PaletteSheets;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FabStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for FabStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FabVariant {
+    /// A round button for a single icon
+    Circular,
+    /// A pill shaped button, wide enough for an icon and a label
+    Extended,
+}
+
+impl Default for FabVariant {
+    fn default() -> Self {
+        Self::Circular
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Properties)]
+pub struct FabProperties {
+    /// Describes the action of a circular button to assistive technology, since it only shows an
+    /// icon. Leave it unset only to label the button otherwise, e.g. with an `aria-labelledby`
+    /// attribute.
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Sheet,
+    /// The icon, and for an extended button the label.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub on_pressed: Callback<ButtonPressedEvent>,
+    #[prop_or_default]
+    pub variant: FabVariant,
+    #[prop_or(ButtonSize::Large)]
+    pub size: ButtonSize,
+    #[prop_or(ButtonColor::Default)]
+    pub color: ButtonColor,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or(RippleBehaviour::Interactive)]
+    pub ripples: RippleBehaviour,
+    #[prop_or(0)]
+    pub tab_index: i32,
+    /// Render the button as a link to the given url, see [`ButtonBase`].
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    #[prop_or_default]
+    pub target: Option<AttrValue>,
+    #[prop_or_default]
+    pub rel: Option<AttrValue>,
+    #[prop_or_default]
+    pub download: Option<AttrValue>,
+    /// The tag name of the element to render, see [`ButtonBase`].
+    #[prop_or_default]
+    pub element: Option<AttrValue>,
+    /// The `type` of the native button, defaulting to [`ButtonType::Button`].
+    #[prop_or_default]
+    pub button_type: ButtonType,
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+    #[prop_or(false)]
+    pub autofocus: bool,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+    /// Bound to actions to programmatically focus the button, see [`ButtonBaseActions`].
+    #[prop_or_default]
+    pub action: ImperativeRef<ButtonBaseActions>,
+}
+
+struct DefaultStyles {
+    root_inline: Sheet,
+    // sizing
+    size_circular_small: Sheet,
+    size_circular_medium: Sheet,
+    size_circular_large: Sheet,
+    size_extended_small: Sheet,
+    size_extended_medium: Sheet,
+    size_extended_large: Sheet,
+    // coloring
+    color_palette: PaletteSheets<Sheet>,
+    color_default: Sheet,
+    color_inherit: Sheet,
+    // override
+    root_override: Sheet,
+}
+
+fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
+    // FIXME: push into theme
+    let gray_300: CssColor = CssColor::rgb(0xe0, 0xe0, 0xe0);
+    let gray_a100: CssColor = CssColor::rgb(0xd5, 0xd5, 0xd5);
+
+    let actions = &theme.palette.actions;
+    let shadows0 = &theme.shadows[0];
+    let shadows6 = &theme.shadows[6];
+    let shadows12 = &theme.shadows[12];
+
+    let root_basebox = sheet!(
+        min-height: 36px;
+        min-width: 0;
+        padding: 0;
+        border-radius: 50%;
+        z-index: ${theme.z_index.fab};
+        box-shadow: ${shadows6};
+        &.${CLASS_FOCUS_VISIBLE} {
+            box-shadow: ${shadows6};
+        }
+        &:active {
+            box-shadow: ${shadows12};
+        }
+        &:hover { text-decoration: none; }
+        &.${CLASS_DISABLED} {
+            color: ${actions.disabled};
+            box-shadow: ${shadows0};
+            background-color: ${actions.disabled_background};
+        }
+    );
+    let root_transition = theme.motion.transition(
+        "background-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        box-shadow 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        border-color 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms",
+    );
+    let mut root_inline = vec![];
+    root_inline.extend_from_slice(&theme.typography.button);
+    root_inline.extend_from_slice(&root_basebox);
+    root_inline.extend_from_slice(&root_transition);
+    let root_inline = Sheet::from(root_inline);
+
+    let size_circular_small = sheet!(
+        width: 40px;
+        height: 40px;
+    );
+    let size_circular_medium = sheet!(
+        width: 48px;
+        height: 48px;
+    );
+    let size_circular_large = sheet!(
+        width: 56px;
+        height: 56px;
+    );
+    let size_extended_small = sheet!(
+        width: auto;
+        height: 34px;
+        min-width: 34px;
+        min-height: auto;
+        padding: 0 8px;
+        border-radius: 17px;
+    );
+    let size_extended_medium = sheet!(
+        width: auto;
+        height: 40px;
+        min-width: 40px;
+        min-height: auto;
+        padding: 0 16px;
+        border-radius: 20px;
+    );
+    let size_extended_large = sheet!(
+        width: auto;
+        height: 48px;
+        min-width: 48px;
+        min-height: auto;
+        padding: 0 16px;
+        border-radius: 24px;
+    );
+
+    let color_default = sheet!(
+        color: ${theme.palette.contrast_text_color(gray_300)};
+        background-color: ${gray_300};
+        &:hover {
+            background-color: ${gray_a100};
+        }
+        @media (hover: none) {
+            &:hover { background-color: ${gray_300}; }
+        }
+    );
+    let color_inherit = sheet!(
+        color: inherit;
+    );
+    let color_palette = PaletteSheets::new(&theme.palette, |spec| {
+        sheet!(
+            color: ${spec.contrast};
+            background-color: ${spec.main};
+            &:hover {
+                background-color: ${spec.dark};
+            }
+            @media (hover: none) {
+                &:hover { background-color: ${spec.main}; }
+            }
+        )
+    });
+
+    let root_override = theme
+        .components
+        .search_override::<FabStyleRoot>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
+
+    DefaultStyles {
+        root_inline,
+        size_circular_small,
+        size_circular_medium,
+        size_circular_large,
+        size_extended_small,
+        size_extended_medium,
+        size_extended_large,
+        color_palette,
+        color_default,
+        color_inherit,
+        root_override,
+    }
+}
+
+impl DefaultStyles {
+    fn build_root_style(&self, props: &FabProperties) -> Vec<ScopeContent> {
+        use ButtonColor::*;
+        use ButtonSize::*;
+        use FabVariant::*;
+
+        let mut collected_scopes = vec![];
+        collected_scopes.extend_from_slice(&self.root_inline);
+        collected_scopes.extend_from_slice(match (props.variant, props.size) {
+            (Circular, Small) => &self.size_circular_small,
+            (Circular, Medium) => &self.size_circular_medium,
+            (Circular, Large) => &self.size_circular_large,
+            (Extended, Small) => &self.size_extended_small,
+            (Extended, Medium) => &self.size_extended_medium,
+            (Extended, Large) => &self.size_extended_large,
+        });
+        collected_scopes.extend_from_slice(match props.color {
+            Default => &self.color_default,
+            Inherit => &self.color_inherit,
+            color => color
+                .palette_color()
+                .and_then(|c| self.color_palette.get(c))
+                .map(|sheet| &**sheet)
+                .unwrap_or_default(),
+        });
+        collected_scopes.extend_from_slice(&self.root_override);
+
+        collected_scopes
+    }
+}
+
+/// A floating action button, for the primary action of a screen.
+///
+/// The button floats above the content with an elevation of 6, raised to 12 while pressed. Label a
+/// circular button, which only shows an icon, with an `aria_label`.
+#[function_component]
+pub fn Fab(props: &FabProperties) -> Html {
+    let styles = use_theme(derive_styles_from_theme);
+
+    let mut root_style = styles.build_root_style(props);
+    root_style.extend_from_slice(&props.class);
+    let root_style = Sheet::from(root_style);
+
+    let mut attributes = props.attributes.clone();
+    if let Some(label) = props.aria_label.clone() {
+        attributes = attributes.attr("aria-label", label);
+    }
+
+    html! {
+        <ButtonBase
+            class={root_style}
+            disabled={props.disabled}
+            on_pressed={props.on_pressed.clone()}
+            ripples={props.ripples}
+            tab_index={props.tab_index}
+            href={props.href.clone()}
+            target={props.target.clone()}
+            rel={props.rel.clone()}
+            download={props.download.clone()}
+            element={props.element.clone()}
+            button_type={props.button_type}
+            name={props.name.clone()}
+            value={props.value.clone()}
+            form={props.form.clone()}
+            autofocus={props.autofocus}
+            {attributes}
+            node_ref={props.node_ref.clone()}
+            action={props.action.clone()}
+        >
+            { for props.children.iter() }
+        </ButtonBase>
+    }
+}
@@ -3,6 +3,18 @@ use std::{
     rc::Rc,
 };
 
+/// A handle through which a component exposes imperative actions, e.g. focusing a button.
+///
+/// The parent creates the ref and passes it as a prop, the child binds its actions with
+/// [`bind_imperative_ref`] when it renders. Refs compare equal if they are clones of each other.
+///
+/// ```
+/// # use material_yewi::hooks::{bind_imperative_ref, ImperativeRef};
+/// let actions = ImperativeRef::<&'static str>::new();
+/// assert!(actions.get().is_none());
+/// bind_imperative_ref(&actions, "bound");
+/// assert_eq!(actions.get().as_deref(), Some(&"bound"));
+/// ```
 #[derive(Clone)]
 pub struct ImperativeRef<T>(Rc<RefCell<Inner<T>>>);
 
@@ -12,6 +24,12 @@ impl<T> Default for ImperativeRef<T> {
     }
 }
 
+impl<T> std::fmt::Debug for ImperativeRef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ImperativeRef")
+    }
+}
+
 impl<T> PartialEq for ImperativeRef<T> {
     fn eq(&self, other: &Self) -> bool {
         // equality for us means that the same binding is done
@@ -34,6 +52,8 @@ impl<T> ImperativeRef<T> {
     pub fn new() -> Self {
         <Self as Default>::default()
     }
+    /// The currently bound value, if any. Clone the value out of the returned borrow before
+    /// invoking actions that rerender the component binding it.
     pub fn get(&self) -> Option<Ref<'_, T>> {
         let r = self.0.borrow();
         // feature(cell_filter_map) would simplify this and get rid of unwrap
@@ -45,6 +65,7 @@ impl<T> ImperativeRef<T> {
     }
 }
 
+/// Bind the value of an imperative ref, replacing the previously bound value.
 pub fn bind_imperative_ref<T>(r: &ImperativeRef<T>, t: T) {
     let mut i = r.0.borrow_mut();
     i.current = Some(t);
@@ -0,0 +1,23 @@
+//! Hooks shared by the components of this crate, for use in custom components.
+mod imperative_ref;
+mod use_controlled;
+mod use_event_callback;
+mod use_focus_visible;
+mod use_fork_ref;
+mod use_id;
+mod use_media_query;
+mod use_scroll_trigger;
+mod use_state_ext;
+
+pub use imperative_ref::{bind_imperative_ref, ImperativeRef};
+pub use use_controlled::use_controlled;
+pub use use_event_callback::use_event_callback;
+pub use use_focus_visible::{use_focus_visible, FocusVisibleHandle};
+pub use use_fork_ref::{use_fork_ref, ForkRef};
+pub use use_id::{use_id, IdProvider, IdProviderProps};
+pub use use_media_query::{
+    use_breakpoint_down, use_breakpoint_up, use_media_query, MediaQueryOptions,
+};
+pub(crate) use use_scroll_trigger::use_optional_scroll_trigger;
+pub use use_scroll_trigger::{use_scroll_trigger, ScrollTarget, ScrollTriggerOptions};
+pub use use_state_ext::UseStateHandleExt;
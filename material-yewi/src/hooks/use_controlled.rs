@@ -0,0 +1,26 @@
+use yew::use_mut_ref;
+use yew::use_state;
+use yew::Callback;
+
+/// The value of a prop that is either controlled by the parent, or kept as internal state when
+/// the parent passes `None`.
+///
+/// Returns the current value and a callback setting the internal state. Setting the value of a
+/// controlled prop does nothing, the parent is expected to pass the new value instead. Whether
+/// the prop is controlled is decided when the component is first rendered.
+#[yew::hook]
+pub fn use_controlled<T: 'static + Clone>(controlled: Option<T>, default: T) -> (T, Callback<T>) {
+    let is_controlled = *use_mut_ref(|| controlled.is_some()).borrow();
+    let state = use_state(move || default);
+
+    let value = match controlled {
+        Some(value) if is_controlled => value,
+        _ => (*state).clone(),
+    };
+    let set_value = Callback::from(move |value| {
+        if !is_controlled {
+            state.set(value);
+        }
+    });
+    (value, set_value)
+}
@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use yew::use_memo;
+use yew::use_mut_ref;
+use yew::Callback;
+
+/// A callback that keeps its identity across renders, but always calls the closure of the latest
+/// render.
+///
+/// Use it to pass event handlers to children or effects without causing rerenders when the
+/// captured values change.
+#[yew::hook]
+pub fn use_event_callback<IN: 'static, F: 'static + Fn(IN)>(f: F) -> Callback<IN> {
+    let f: Rc<dyn Fn(IN)> = Rc::new(f);
+    let latest = use_mut_ref(|| f.clone());
+    *latest.borrow_mut() = f;
+
+    let callback = use_memo(
+        move |_| {
+            Callback::from(move |input| {
+                let f = latest.borrow().clone();
+                f(input)
+            })
+        },
+        (),
+    );
+    (*callback).clone()
+}
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::JsCast;
@@ -8,6 +8,7 @@ use web_sys::KeyboardEvent;
 use web_sys::Node;
 use web_sys::VisibilityState;
 use web_sys::{HtmlElement, HtmlInputElement, HtmlTextAreaElement};
+use yew::use_effect;
 use yew::use_effect_with_deps;
 use yew::use_mut_ref;
 use yew::Callback;
@@ -16,9 +17,13 @@ use yew::NodeRef;
 
 use crate::bindings::{clear_timeout, set_timeout, TimeoutHandle};
 
+/// Returned by [`use_focus_visible`].
 pub struct FocusVisibleHandle {
+    /// To be called when the element loses focus
     pub onblur: Callback<FocusEvent>,
+    /// To be called when the element receives focus
     pub onfocus: Callback<FocusEvent>,
+    /// Whether the element currently shows a visible focus indicator, updated by the callbacks
     pub is_visible: Rc<RefCell<bool>>,
 }
 
@@ -50,7 +55,10 @@ impl GlobalHackState {
     }
 }
 
-struct GlobalFocusVisibleHack {
+/// Tracks keyboard interaction in a document, to polyfill `:focus-visible` in browsers without
+/// native support. Shared by all consumers in the same document, the listeners are removed when
+/// the last consumer drops its reference.
+struct FocusVisibleTracker {
     state: Rc<RefCell<GlobalHackState>>,
     doc: Document,
     on_keydown: Closure<dyn FnMut(KeyboardEvent)>,
@@ -58,7 +66,31 @@ struct GlobalFocusVisibleHack {
     on_visiblitychange: Closure<dyn FnMut()>,
 }
 
-impl GlobalFocusVisibleHack {
+const POINTERDOWN_EVENTS: [&str; 3] = ["mousedown", "pointerdown", "touchstart"];
+
+thread_local!(
+    /// The tracker of each document, if one is currently in use.
+    static TRACKERS: RefCell<Vec<(Document, Weak<FocusVisibleTracker>)>> = RefCell::new(vec![]);
+);
+
+impl FocusVisibleTracker {
+    /// Get the tracker of the document, installing it if there is none.
+    fn for_document(doc: &Document) -> Rc<Self> {
+        TRACKERS.with(|trackers| {
+            let mut trackers = trackers.borrow_mut();
+            trackers.retain(|(_, tracker)| tracker.strong_count() > 0);
+            let existing = trackers
+                .iter()
+                .find(|(d, _)| d == doc)
+                .and_then(|(_, tracker)| tracker.upgrade());
+            existing.unwrap_or_else(|| {
+                let tracker = Rc::new(Self::new(doc.clone()));
+                trackers.push((doc.clone(), Rc::downgrade(&tracker)));
+                tracker
+            })
+        })
+    }
+
     fn new(doc: Document) -> Self {
         let state = GlobalHackState::new();
         let state_capture = state.clone();
@@ -84,20 +116,24 @@ impl GlobalFocusVisibleHack {
             }
         }) as Box<dyn FnMut()>);
 
-        doc.add_event_listener_with_callback("onkeydown", on_keydown.as_ref().unchecked_ref())
-            .unwrap();
-        doc.add_event_listener_with_callback("mousedown", on_pointerdown.as_ref().unchecked_ref())
-            .unwrap();
-        doc.add_event_listener_with_callback(
-            "pointerdown",
-            on_pointerdown.as_ref().unchecked_ref(),
+        doc.add_event_listener_with_callback_and_bool(
+            "keydown",
+            on_keydown.as_ref().unchecked_ref(),
+            true,
         )
         .unwrap();
-        doc.add_event_listener_with_callback("touchstart", on_pointerdown.as_ref().unchecked_ref())
+        for event in POINTERDOWN_EVENTS {
+            doc.add_event_listener_with_callback_and_bool(
+                event,
+                on_pointerdown.as_ref().unchecked_ref(),
+                true,
+            )
             .unwrap();
-        doc.add_event_listener_with_callback(
+        }
+        doc.add_event_listener_with_callback_and_bool(
             "visibilitychange",
             on_visiblitychange.as_ref().unchecked_ref(),
+            true,
         )
         .unwrap();
         Self {
@@ -138,97 +174,125 @@ impl GlobalFocusVisibleHack {
     }
 }
 
-impl Drop for GlobalFocusVisibleHack {
+impl Drop for FocusVisibleTracker {
     fn drop(&mut self) {
         let Self {
+            state,
             doc,
             on_keydown,
             on_pointerdown,
             on_visiblitychange,
-            ..
         } = self;
 
-        doc.remove_event_listener_with_callback("onkeydown", on_keydown.as_ref().unchecked_ref())
-            .unwrap();
-        doc.remove_event_listener_with_callback(
-            "mousedown",
-            on_pointerdown.as_ref().unchecked_ref(),
-        )
-        .unwrap();
-        doc.remove_event_listener_with_callback(
-            "pointerdown",
-            on_pointerdown.as_ref().unchecked_ref(),
-        )
-        .unwrap();
-        doc.remove_event_listener_with_callback(
-            "touchstart",
-            on_pointerdown.as_ref().unchecked_ref(),
+        doc.remove_event_listener_with_callback_and_bool(
+            "keydown",
+            on_keydown.as_ref().unchecked_ref(),
+            true,
         )
         .unwrap();
-        doc.remove_event_listener_with_callback(
+        for event in POINTERDOWN_EVENTS {
+            doc.remove_event_listener_with_callback_and_bool(
+                event,
+                on_pointerdown.as_ref().unchecked_ref(),
+                true,
+            )
+            .unwrap();
+        }
+        doc.remove_event_listener_with_callback_and_bool(
             "visibilitychange",
             on_visiblitychange.as_ref().unchecked_ref(),
+            true,
         )
         .unwrap();
+        if let Some(t) = state.borrow_mut().focus_recently_timeout.take() {
+            clear_timeout(t);
+        }
     }
 }
 
+/// How focus visibility is determined for the document of a node.
 #[derive(Clone)]
-struct DocumentHandle(Rc<RefCell<Option<GlobalFocusVisibleHack>>>);
-impl DocumentHandle {
+enum FocusVisibleSource {
+    /// The browser supports `:focus-visible`
+    Native,
+    /// The polyfill tracks keyboard interaction
+    Polyfill(Rc<FocusVisibleTracker>),
+}
+
+impl FocusVisibleSource {
+    fn for_node(node: &Node) -> Option<Self> {
+        let doc = node.owner_document()?;
+        // Browsers without support for :focus-visible throw on the unknown selector
+        let is_native = doc
+            .document_element()
+            .map_or(false, |e| e.matches(":focus-visible").is_ok());
+        Some(if is_native {
+            Self::Native
+        } else {
+            Self::Polyfill(FocusVisibleTracker::for_document(&doc))
+        })
+    }
+
     fn is_focus_visible(&self, event: &FocusEvent) -> bool {
-        let target = event.target().unwrap().dyn_into::<HtmlElement>().unwrap();
         // focus visible event always have an Element as target
-        if let Ok(true) = target.matches(":focus-visible") {
-            return true;
-        }
-        if let Some(ref global_hook) = *self.0.borrow() {
-            global_hook.is_focus_visible(&target)
-        } else {
-            // Otherwise return false, maybe panic here?
-            false
+        let target = event.target().unwrap().dyn_into::<HtmlElement>().unwrap();
+        match self {
+            Self::Native => target.matches(":focus-visible").unwrap_or(false),
+            Self::Polyfill(tracker) => tracker.is_focus_visible(&target),
         }
     }
 }
 
-fn setup(handle: DocumentHandle, node: Node) -> impl FnOnce() {
-    let doc = node.owner_document().expect("node contained in a document");
-    // TODO: cache per document, then drop in returned FnOnce
-    // TODO: can skip this setup if :focus-visible is supported
-    *handle.0.borrow_mut() = Some(GlobalFocusVisibleHack::new(doc));
-    move || {}
-}
-
+/// Track whether the focus of an element should be indicated visibly, like `:focus-visible`.
+///
+/// Focus is visible when it was moved by the keyboard, not by a pointer. Browsers supporting
+/// `:focus-visible` are asked directly, otherwise keyboard interaction is tracked in the
+/// document of the element. Connect `onfocus` and `onblur` of the returned handle to the
+/// focus events of the element.
+///
+/// Tracking follows the element bound to `node`, not the ref itself, so passing a different
+/// `NodeRef` bound to the same element keeps the tracked state.
 #[yew::hook]
 pub fn use_focus_visible(node: &NodeRef) -> FocusVisibleHandle {
     let is_visible = use_mut_ref(|| false);
-    let doc_handle = DocumentHandle(Rc::default());
+    let source = use_mut_ref(|| Option::<FocusVisibleSource>::None);
+    let bound = use_mut_ref(|| Option::<Node>::None);
 
-    let doc_handle_capture = doc_handle.clone();
+    let source_capture = source.clone();
+    let node_capture = node.clone();
+    use_effect(move || {
+        let node = node_capture.get();
+        if *bound.borrow() != node {
+            // The new source is acquired before the old one is released, keeping a shared
+            // tracker and its state alive when the element stays in the same document
+            let next = node.as_ref().and_then(FocusVisibleSource::for_node);
+            *source_capture.borrow_mut() = next;
+            *bound.borrow_mut() = node;
+        }
+        || {}
+    });
+    let source_capture = source.clone();
     use_effect_with_deps(
-        |node| {
-            let teardown = node.get().map(|n| setup(doc_handle_capture, n));
-            || {
-                if let Some(t) = teardown {
-                    t()
-                }
-            }
+        move |_| {
+            // Releases the shared tracker, removing its listeners if this was the last consumer
+            move || *source_capture.borrow_mut() = None
         },
-        node.clone(),
+        (),
     );
 
     let is_visible_capture_focus = is_visible.clone();
     let is_visible_capture_blur = is_visible.clone();
-    let doc_handle_capture = doc_handle.clone();
+    let source_capture = source.clone();
     let onblur = Callback::from(move |_| {
         if std::mem::replace(&mut is_visible_capture_blur.borrow_mut(), false) {
-            if let Some(ref global) = *doc_handle_capture.0.borrow() {
-                GlobalHackState::set_had_focus_recently(&global.state);
+            if let Some(FocusVisibleSource::Polyfill(ref tracker)) = *source_capture.borrow() {
+                GlobalHackState::set_had_focus_recently(&tracker.state);
             }
         }
     });
     let onfocus = Callback::from(move |ev| {
-        if doc_handle.is_focus_visible(&ev) {
+        let source = source.borrow().clone();
+        if source.map_or(false, |s| s.is_focus_visible(&ev)) {
             *is_visible_capture_focus.borrow_mut() = true;
         }
     });
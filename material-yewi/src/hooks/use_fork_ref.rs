@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use yew::function_component;
+use yew::html;
+use yew::use_memo;
+use yew::Children;
+use yew::Html;
+use yew::NodeRef;
+use yew::Properties;
+
+/// Binds the root element of some html to several [`NodeRef`]s, see [`use_fork_ref`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForkRef {
+    refs: Rc<Vec<NodeRef>>,
+}
+
+impl ForkRef {
+    /// Bind the first node `html` renders to every forked ref.
+    pub fn bind(&self, html: Html) -> Html {
+        self.refs.iter().fold(html, |html, node_ref| {
+            html! { <RefHost ref={node_ref.clone()}>{html}</RefHost> }
+        })
+    }
+}
+
+#[derive(PartialEq, Properties)]
+struct RefHostProps {
+    children: Children,
+}
+
+/// Renders its children unchanged. The `ref` of a component is bound to the first node it
+/// renders, which is how a single element is bound to more than one ref.
+#[function_component]
+fn RefHost(props: &RefHostProps) -> Html {
+    html! { <>{ for props.children.iter() }</> }
+}
+
+/// Bind an element to every one of the given [`NodeRef`]s.
+///
+/// An element can only be given a single `ref`. Components that need to access their root
+/// element, and forward the `node_ref` of their props to it as well, render the root through
+/// [`ForkRef::bind`] instead.
+///
+/// ```
+/// # use material_yewi::hooks::use_fork_ref;
+/// # use yew::{function_component, html, use_node_ref, Html, NodeRef, Properties};
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///     node_ref: NodeRef,
+/// }
+///
+/// #[function_component]
+/// fn Component(props: &Props) -> Html {
+///     let own_ref = use_node_ref();
+///     let fork = use_fork_ref(&[own_ref, props.node_ref.clone()]);
+///     fork.bind(html! { <div /> })
+/// }
+/// ```
+#[yew::hook]
+pub fn use_fork_ref(refs: &[NodeRef]) -> ForkRef {
+    let refs = use_memo(|refs| refs.clone(), refs.to_vec());
+    ForkRef { refs }
+}
@@ -0,0 +1,107 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use yew::function_component;
+use yew::html;
+use yew::use_context;
+use yew::use_memo;
+use yew::virtual_dom::AttrValue;
+use yew::Children;
+use yew::ContextProvider;
+use yew::Html;
+use yew::Properties;
+
+/// Ids handed out to components without an [`IdProvider`]. They are numbered across the whole
+/// process, so they are not deterministic: the server and a hydrating client, or two renders of
+/// the same tree, hand out different ids.
+static NEXT_FALLBACK_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug)]
+struct IdContext {
+    prefix: AttrValue,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl PartialEq for IdContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && Rc::ptr_eq(&self.next_id, &other.next_id)
+    }
+}
+
+impl IdContext {
+    fn generate(&self) -> AttrValue {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        format!("{}-{}", self.prefix, id).into()
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct IdProviderProps {
+    /// The prefix of generated ids. Defaults to an id generated by the enclosing provider, or
+    /// `"mwi"` for the outermost provider.
+    #[prop_or_default]
+    pub prefix: Option<AttrValue>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Generates deterministic ids for [`use_id`] in its children.
+///
+/// Ids are numbered in the order the components are first rendered. Server side rendering and
+/// hydration render the tree in the same order, so the generated ids match. Wrap the root of
+/// the application, and subtrees that are only rendered later, e.g. after loading data, in a
+/// provider, so that their ids do not depend on what was rendered before.
+#[function_component]
+pub fn IdProvider(props: &IdProviderProps) -> Html {
+    let parent = use_context::<IdContext>();
+    let context = use_memo(
+        move |prefix| IdContext {
+            prefix: prefix.clone().unwrap_or_else(|| match parent {
+                Some(parent) => parent.generate(),
+                None => "mwi".into(),
+            }),
+            next_id: Rc::default(),
+        },
+        props.prefix.clone(),
+    );
+    html! {
+        <ContextProvider<IdContext> context={(*context).clone()}>
+            { for props.children.iter() }
+        </ContextProvider<IdContext>>
+    }
+}
+
+/// An id for an element, e.g. to connect it to a label with `aria-labelledby`.
+///
+/// Returns `id_override` if given, otherwise an id generated for the component, which stays
+/// the same across renders. Generated ids are deterministic inside an [`IdProvider`], and match
+/// between server side rendering and hydration.
+///
+/// **Outside of an [`IdProvider`], the generated ids are not deterministic.** They have the form
+/// `mwi-fallback-N` with a counter shared by all components, so ids differ between the server
+/// and the hydrating client, and hydration fails to connect labels. Debug builds warn once when
+/// a fallback id is handed out.
+#[yew::hook]
+pub fn use_id(id_override: Option<AttrValue>) -> AttrValue {
+    let context = use_context::<IdContext>();
+    let generated = use_memo(
+        |context| match context {
+            Some(context) => context.generate(),
+            None => {
+                let id = NEXT_FALLBACK_ID.fetch_add(1, Ordering::Relaxed);
+                if cfg!(debug_assertions) && id == 0 {
+                    gloo::console::warn!(
+                        "use_id was called outside of an IdProvider, the generated ids are not \
+                         deterministic and will not match between server side rendering and \
+                         hydration"
+                    );
+                }
+                format!("mwi-fallback-{}", id).into()
+            }
+        },
+        context,
+    );
+    id_override.unwrap_or_else(|| (*generated).clone())
+}
@@ -0,0 +1,68 @@
+use gloo::events::EventListener;
+use gloo::utils::window;
+use material_styles_yew::{Breakpoint, Theme};
+use web_sys::MediaQueryList;
+use yew::use_context;
+use yew::use_effect_with_deps;
+use yew::use_state;
+
+use super::UseStateHandleExt;
+
+/// Options for [`use_media_query`] and the breakpoint hooks.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct MediaQueryOptions {
+    /// Whether the query matches while it can not be evaluated, i.e. during server side rendering
+    /// and the first render during hydration.
+    pub default_matches: bool,
+    /// Evaluate the query already during the first render. Only use this when the component is
+    /// never rendered on the server, otherwise hydration might not match.
+    pub no_ssr: bool,
+}
+
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    window().match_media(query).ok().flatten()
+}
+
+/// Whether a css media query, e.g. `"(prefers-color-scheme: dark)"`, matches the document.
+///
+/// The component rerenders whenever the result changes.
+#[yew::hook]
+pub fn use_media_query(query: &str, options: MediaQueryOptions) -> bool {
+    let matches = use_state(|| {
+        if options.no_ssr {
+            match_media(query).map_or(options.default_matches, |m| m.matches())
+        } else {
+            options.default_matches
+        }
+    });
+
+    let matches_capture = matches.clone();
+    use_effect_with_deps(
+        move |query: &String| {
+            let listener = match_media(query).map(|query_list| {
+                matches_capture.relaxed_set(query_list.matches());
+                let query_list_capture = query_list.clone();
+                EventListener::new(&query_list, "change", move |_| {
+                    matches_capture.relaxed_set(query_list_capture.matches());
+                })
+            });
+            move || drop(listener)
+        },
+        query.to_string(),
+    );
+    *matches
+}
+
+/// Whether the viewport is at least as wide as the breakpoint of the current theme.
+#[yew::hook]
+pub fn use_breakpoint_up(key: Breakpoint, options: MediaQueryOptions) -> bool {
+    let theme = use_context::<Theme>().unwrap_or_default();
+    use_media_query(&theme.breakpoints.up(key), options)
+}
+
+/// Whether the viewport is narrower than the breakpoint of the current theme.
+#[yew::hook]
+pub fn use_breakpoint_down(key: Breakpoint, options: MediaQueryOptions) -> bool {
+    let theme = use_context::<Theme>().unwrap_or_default();
+    use_media_query(&theme.breakpoints.down(key), options)
+}
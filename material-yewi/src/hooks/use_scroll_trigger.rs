@@ -0,0 +1,106 @@
+use gloo::events::EventListener;
+use gloo::utils::window;
+use web_sys::{Element, EventTarget};
+use yew::use_effect_with_deps;
+use yew::use_mut_ref;
+use yew::use_state;
+use yew::NodeRef;
+
+use super::UseStateHandleExt;
+
+/// The scrolling container observed by [`use_scroll_trigger`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScrollTarget {
+    /// The window, i.e. the document is scrolled
+    Window,
+    /// A scrollable element
+    Element(NodeRef),
+}
+
+impl Default for ScrollTarget {
+    fn default() -> Self {
+        Self::Window
+    }
+}
+
+impl ScrollTarget {
+    fn event_target(&self) -> Option<EventTarget> {
+        match self {
+            Self::Window => Some(window().into()),
+            Self::Element(node) => node.cast::<EventTarget>(),
+        }
+    }
+
+    fn scroll_position(&self) -> f64 {
+        match self {
+            Self::Window => window().page_y_offset().unwrap_or_default(),
+            Self::Element(node) => node.cast::<Element>().map_or(0., |e| e.scroll_top() as f64),
+        }
+    }
+}
+
+/// Options for [`use_scroll_trigger`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrollTriggerOptions {
+    /// The container whose scrolling is observed
+    pub target: ScrollTarget,
+    /// The scroll position, in pixels, beyond which the trigger fires
+    pub threshold: f64,
+    /// By default, scrolling back up releases the trigger even beyond the threshold. Disable the
+    /// hysteresis to only compare the scroll position with the threshold.
+    pub disable_hysteresis: bool,
+}
+
+impl Default for ScrollTriggerOptions {
+    fn default() -> Self {
+        Self {
+            target: ScrollTarget::Window,
+            threshold: 100.,
+            disable_hysteresis: false,
+        }
+    }
+}
+
+/// Whether the user scrolled beyond a threshold, e.g. to hide or elevate an app bar.
+///
+/// The component rerenders whenever the trigger changes.
+#[yew::hook]
+pub fn use_scroll_trigger(options: ScrollTriggerOptions) -> bool {
+    use_optional_scroll_trigger(Some(options))
+}
+
+/// Like [`use_scroll_trigger`], but does not listen to scrolling at all for `None`.
+#[yew::hook]
+pub(crate) fn use_optional_scroll_trigger(options: Option<ScrollTriggerOptions>) -> bool {
+    let trigger = use_state(|| false);
+    let last_position = use_mut_ref(|| Option::<f64>::None);
+
+    let trigger_capture = trigger.clone();
+    use_effect_with_deps(
+        move |options: &Option<ScrollTriggerOptions>| {
+            let listener = options.clone().and_then(|options| {
+                let target = options.target.event_target()?;
+                let trigger_capture = trigger_capture.clone();
+                let update = move || {
+                    let position = options.target.scroll_position();
+                    let previous = last_position.replace(Some(position));
+                    let scrolling_up = matches!(previous, Some(p) if position < p);
+                    let triggered = if !options.disable_hysteresis && scrolling_up {
+                        false
+                    } else {
+                        position > options.threshold
+                    };
+                    trigger_capture.relaxed_set(triggered);
+                };
+                update();
+                Some(EventListener::new(&target, "scroll", move |_| update()))
+            });
+            if listener.is_none() {
+                trigger_capture.relaxed_set(false);
+            }
+            move || drop(listener)
+        },
+        options,
+    );
+    *trigger
+}
@@ -1,8 +1,10 @@
 use std::ops::Deref;
 use yew::functional::UseStateHandle;
 
+/// Extensions for the handle returned by `use_state`.
 pub trait UseStateHandleExt<T> {
-    /// Set the value if the current value is not already equal to the value
+    /// Set the value if the current value is not already equal to the value, avoiding a rerender
+    /// otherwise.
     fn relaxed_set(&self, value: T);
 }
 
@@ -0,0 +1,298 @@
+use material_styles_yew::use_theme;
+use material_styles_yew::Theme;
+use stylist::ast::{sheet, ScopeContent, Sheet};
+use yew::function_component;
+use yew::html;
+use yew::virtual_dom::AttrValue;
+use yew::Callback;
+use yew::Children;
+use yew::Html;
+use yew::NodeRef;
+use yew::Properties;
+
+use crate::attributes::ExtraAttributes;
+use crate::button::{ButtonColor, ButtonSize};
+use crate::button_base::{
+    ButtonBase, ButtonBaseActions, ButtonPressedEvent, ButtonType, ImperativeRef, RippleBehaviour,
+    CLASS_DISABLED,
+};
+use crate::palette_sheets::PaletteSheets;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconButtonStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for IconButtonStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// Aligns the icon of a button at the edge of its container, e.g. a [`Toolbar`], by offsetting
+/// the padding of the button with a negative margin.
+///
+/// [`Toolbar`]: crate::toolbar::Toolbar
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum IconButtonEdge {
+    Start,
+    End,
+}
+
+#[derive(Clone, PartialEq, Debug, Properties)]
+pub struct IconButtonProperties {
+    /// Describes the action of the button to assistive technology, since the icon has no text.
+    /// Leave it unset only to label the button otherwise, e.g. with an `aria-labelledby`
+    /// attribute.
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Sheet,
+    /// The icon to show.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub on_pressed: Callback<ButtonPressedEvent>,
+    #[prop_or(ButtonColor::Default)]
+    pub color: ButtonColor,
+    #[prop_or_default]
+    pub size: ButtonSize,
+    #[prop_or_default]
+    pub edge: Option<IconButtonEdge>,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or(RippleBehaviour::Centered)]
+    pub ripples: RippleBehaviour,
+    #[prop_or(0)]
+    pub tab_index: i32,
+    /// Render the button as a link to the given url, see [`ButtonBase`].
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    #[prop_or_default]
+    pub target: Option<AttrValue>,
+    #[prop_or_default]
+    pub rel: Option<AttrValue>,
+    #[prop_or_default]
+    pub download: Option<AttrValue>,
+    /// The tag name of the element to render, see [`ButtonBase`].
+    #[prop_or_default]
+    pub element: Option<AttrValue>,
+    /// The `type` of the native button, defaulting to [`ButtonType::Button`].
+    #[prop_or_default]
+    pub button_type: ButtonType,
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+    #[prop_or(false)]
+    pub autofocus: bool,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+    /// Bound to actions to programmatically focus the button, see [`ButtonBaseActions`].
+    #[prop_or_default]
+    pub action: ImperativeRef<ButtonBaseActions>,
+}
+
+struct DefaultStyles {
+    root_style: Sheet,
+    // coloring
+    color_palette: PaletteSheets<Sheet>,
+    color_default: Sheet,
+    color_inherit: Sheet,
+    // sizing
+    size_small: Sheet,
+    size_medium: Sheet,
+    size_large: Sheet,
+    // edges
+    edge_start: Sheet,
+    edge_end: Sheet,
+    edge_start_small: Sheet,
+    edge_end_small: Sheet,
+    // override
+    root_override: Sheet,
+}
+
+fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
+    let actions = &theme.palette.actions;
+    let root_basebox = sheet!(
+        text-align: center;
+        flex: 0 0 auto;
+        border-radius: 50%;
+        overflow: visible;
+        &.${CLASS_DISABLED} {
+            background-color: transparent;
+            color: ${actions.disabled};
+        }
+    );
+    let root_transition = theme
+        .motion
+        .transition("background-color 150ms cubic-bezier(0.4, 0, 0.2, 1) 0ms");
+    let mut root_style = vec![];
+    root_style.extend_from_slice(&root_basebox);
+    root_style.extend_from_slice(&root_transition);
+    let root_style = Sheet::from(root_style);
+
+    let color_default = sheet!(
+        color: ${actions.active};
+        &:hover {
+            background-color: ${theme.palette.text.primary.alpha_multiply(actions.hover_opacity)};
+        }
+        @media (hover: none) {
+            &:hover { background-color: transparent; }
+        }
+    );
+    let color_inherit = sheet!(
+        color: inherit;
+        &:hover {
+            background-color: ${theme.palette.text.primary.alpha_multiply(actions.hover_opacity)};
+        }
+        @media (hover: none) {
+            &:hover { background-color: transparent; }
+        }
+    );
+    let color_palette = PaletteSheets::new(&theme.palette, |spec| {
+        sheet!(
+            color: ${spec.main};
+            &:hover {
+                background-color: ${spec.main.alpha_multiply(actions.hover_opacity)};
+            }
+            @media (hover: none) {
+                &:hover { background-color: transparent; }
+            }
+        )
+    });
+
+    let size_small = sheet!(
+        padding: 5px;
+        font-size: ${theme.typography.pixels_to_rem(18.0)};
+    );
+    let size_medium = sheet!(
+        padding: 8px;
+        font-size: ${theme.typography.pixels_to_rem(24.0)};
+    );
+    let size_large = sheet!(
+        padding: 12px;
+        font-size: ${theme.typography.pixels_to_rem(28.0)};
+    );
+
+    let edge_start = sheet!(
+        margin-left: -12px;
+    );
+    let edge_end = sheet!(
+        margin-right: -12px;
+    );
+    let edge_start_small = sheet!(
+        margin-left: -3px;
+    );
+    let edge_end_small = sheet!(
+        margin-right: -3px;
+    );
+
+    let root_override = theme
+        .components
+        .search_override::<IconButtonStyleRoot>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
+
+    DefaultStyles {
+        root_style,
+        color_palette,
+        color_default,
+        color_inherit,
+        size_small,
+        size_medium,
+        size_large,
+        edge_start,
+        edge_end,
+        edge_start_small,
+        edge_end_small,
+        root_override,
+    }
+}
+
+impl DefaultStyles {
+    fn build_root_style(&self, props: &IconButtonProperties) -> Vec<ScopeContent> {
+        use ButtonColor::*;
+        use ButtonSize::*;
+        use IconButtonEdge::*;
+
+        let mut collected_scopes = vec![];
+        collected_scopes.extend_from_slice(&self.root_style);
+        collected_scopes.extend_from_slice(match props.color {
+            Default => &self.color_default,
+            Inherit => &self.color_inherit,
+            color => color
+                .palette_color()
+                .and_then(|c| self.color_palette.get(c))
+                .map(|sheet| &**sheet)
+                .unwrap_or_default(),
+        });
+        collected_scopes.extend_from_slice(match props.size {
+            Small => &self.size_small,
+            Medium => &self.size_medium,
+            Large => &self.size_large,
+        });
+        collected_scopes.extend_from_slice(match (props.edge, props.size) {
+            (Some(Start), Small) => &self.edge_start_small,
+            (Some(End), Small) => &self.edge_end_small,
+            (Some(Start), _) => &self.edge_start,
+            (Some(End), _) => &self.edge_end,
+            (None, _) => &[],
+        });
+        collected_scopes.extend_from_slice(&self.root_override);
+
+        collected_scopes
+    }
+}
+
+/// A circular button showing only an icon, e.g. in a [`Toolbar`].
+///
+/// Since the icon has no text, label the button with an `aria_label` describing the action, or
+/// reference a label with an `aria-labelledby` attribute.
+///
+/// [`Toolbar`]: crate::toolbar::Toolbar
+#[function_component]
+pub fn IconButton(props: &IconButtonProperties) -> Html {
+    let styles = use_theme(derive_styles_from_theme);
+
+    let mut root_style = styles.build_root_style(props);
+    root_style.extend_from_slice(&props.class);
+    let root_style = Sheet::from(root_style);
+
+    let mut attributes = props.attributes.clone();
+    if let Some(label) = props.aria_label.clone() {
+        attributes = attributes.attr("aria-label", label);
+    }
+
+    html! {
+        <ButtonBase
+            class={root_style}
+            disabled={props.disabled}
+            on_pressed={props.on_pressed.clone()}
+            ripples={props.ripples}
+            tab_index={props.tab_index}
+            href={props.href.clone()}
+            target={props.target.clone()}
+            rel={props.rel.clone()}
+            download={props.download.clone()}
+            element={props.element.clone()}
+            button_type={props.button_type}
+            name={props.name.clone()}
+            value={props.value.clone()}
+            form={props.form.clone()}
+            autofocus={props.autofocus}
+            {attributes}
+            node_ref={props.node_ref.clone()}
+            action={props.action.clone()}
+        >
+            { for props.children.iter() }
+        </ButtonBase>
+    }
+}
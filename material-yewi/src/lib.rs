@@ -1,12 +1,19 @@
 mod bindings;
-mod utils;
+mod palette_sheets;
 
 pub mod app_bar;
+pub mod attributes;
 pub mod button;
 pub mod button_base;
 pub mod button_group;
+pub mod fab;
+pub mod hooks;
+pub mod icon_button;
 #[cfg(feature = "router")]
 pub mod link;
+pub mod loading_button;
 pub mod paper;
 pub mod toolbar;
+pub mod top_app_bar;
+pub mod touch_ripple;
 pub mod typography;
@@ -0,0 +1,220 @@
+use lazy_static::lazy_static;
+use material_styles_yew::use_theme;
+use material_styles_yew::Theme;
+use material_styles_yew::{use_keyframes, Keyframes};
+use stylist::ast::{sheet, Sheet};
+use stylist::yew::use_style;
+use yew::classes;
+use yew::function_component;
+use yew::html;
+use yew::Children;
+use yew::Html;
+use yew::Properties;
+
+use crate::button::{Button, ButtonProperties, CLASS_DISABLED};
+
+lazy_static! {
+    static ref KEYFRAMES_SPIN: Keyframes = Keyframes::new("mwi-loading-spin", |name| {
+        sheet!(
+            "@keyframes ${name} {
+                0% {
+                    transform: rotate(0deg);
+                }
+                100% {
+                    transform: rotate(360deg);
+                }
+            }",
+            name = name
+        )
+    });
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadingButtonStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for LoadingButtonStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// Where the loading indicator is shown while loading.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum LoadingPosition {
+    /// In place of the start icon. Set a `start_icon` to keep the width of the button.
+    Start,
+    /// In place of the end icon. Set an `end_icon` to keep the width of the button.
+    End,
+    /// Over the label, which is hidden but keeps its space.
+    Center,
+}
+
+impl Default for LoadingPosition {
+    fn default() -> Self {
+        Self::Center
+    }
+}
+
+fn default_button() -> ButtonProperties {
+    yew::props!(ButtonProperties {})
+}
+
+#[derive(Clone, PartialEq, Debug, Properties)]
+pub struct LoadingButtonProperties {
+    /// Show the loading indicator and disable the button.
+    #[prop_or(false)]
+    pub loading: bool,
+    #[prop_or_default]
+    pub loading_position: LoadingPosition,
+    /// Replaces the default spinning indicator.
+    #[prop_or_default]
+    pub loading_indicator: Option<Html>,
+    /// The label of the button, shown after the children of [`Self::button`].
+    #[prop_or_default]
+    pub children: Children,
+    /// The properties of the underlying [`Button`], e.g. its variant, color and icons.
+    #[prop_or_else(default_button)]
+    pub button: ButtonProperties,
+}
+
+struct DefaultStyles {
+    spinner: Sheet,
+    indicator_center: Sheet,
+    icon_slot: Sheet,
+    icon_hidden: Sheet,
+    indicator_in_slot: Sheet,
+    loading_center: Sheet,
+    root_override: Sheet,
+}
+
+fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
+    let disabled_color = &theme.palette.actions.disabled;
+    let spin = KEYFRAMES_SPIN.name();
+
+    let mut spinner = sheet!(
+        display: inline-block;
+        box-sizing: border-box;
+        width: 16px;
+        height: 16px;
+        border: 2px solid currentColor;
+        border-right-color: transparent;
+        border-radius: 50%;
+    )
+    .to_vec();
+    spinner.extend_from_slice(
+        &theme
+            .motion
+            .animation(&format!("{} 750ms linear infinite", spin)),
+    );
+    let spinner = Sheet::from(spinner);
+    // The label keeps its space, but is hidden behind the indicator
+    let indicator_center = sheet!(
+        position: absolute;
+        left: 50%;
+        top: 50%;
+        transform: translate(-50%, -50%);
+        display: inherit;
+        color: ${disabled_color};
+    );
+    let loading_center = sheet!(
+        &.${CLASS_DISABLED} {
+            color: transparent;
+        }
+    );
+    let icon_slot = sheet!(
+        position: relative;
+        display: inherit;
+    );
+    let icon_hidden = sheet!(
+        display: inherit;
+        visibility: hidden;
+    );
+    let indicator_in_slot = sheet!(
+        position: absolute;
+        top: 50%;
+        left: 50%;
+        transform: translate(-50%, -50%);
+        display: inherit;
+    );
+
+    let root_override = theme
+        .components
+        .search_override::<LoadingButtonStyleRoot>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
+
+    DefaultStyles {
+        spinner,
+        indicator_center,
+        icon_slot,
+        icon_hidden,
+        indicator_in_slot,
+        loading_center,
+        root_override,
+    }
+}
+
+/// A [`Button`] showing a loading indicator while a request is running. While loading, the
+/// button is disabled and keeps its width.
+#[function_component]
+pub fn LoadingButton(props: &LoadingButtonProperties) -> Html {
+    use LoadingPosition::*;
+
+    use_keyframes(&KEYFRAMES_SPIN);
+    let styles = use_theme(derive_styles_from_theme);
+    let spinner_style = use_style(/* "Mwi-loading-spinner", */ styles.spinner.clone());
+    let center_style = use_style(
+        /* "Mwi-loading-center", */ styles.indicator_center.clone(),
+    );
+    let slot_style = use_style(/* "Mwi-loading-slot", */ styles.icon_slot.clone());
+    let hidden_style = use_style(/* "Mwi-loading-hidden", */ styles.icon_hidden.clone());
+    let in_slot_style = use_style(
+        /* "Mwi-loading-in-slot", */ styles.indicator_in_slot.clone(),
+    );
+
+    let mut button = props.button.clone();
+    let mut root_style = styles.root_override.to_vec();
+    if props.loading && props.loading_position == Center {
+        root_style.extend_from_slice(&styles.loading_center);
+    }
+    root_style.extend_from_slice(&button.class);
+    button.class = Sheet::from(root_style);
+
+    let indicator = props.loading_indicator.clone().unwrap_or_else(|| {
+        html! { <span class={classes![spinner_style]} /> }
+    });
+    // The icon keeps its space, the indicator is shown on top of it
+    let icon_with_indicator = |icon: Option<Html>| {
+        html! {
+            <span class={classes![slot_style.clone()]}>
+                <span class={classes![hidden_style.clone()]}>{ for icon }</span>
+                <span class={classes![in_slot_style.clone()]}>{ indicator.clone() }</span>
+            </span>
+        }
+    };
+    match (props.loading, props.loading_position) {
+        (true, Start) => button.start_icon = Some(icon_with_indicator(button.start_icon.take())),
+        (true, End) => button.end_icon = Some(icon_with_indicator(button.end_icon.take())),
+        _ => {}
+    }
+    let center_indicator = (props.loading && props.loading_position == Center).then(|| {
+        html! { <span class={classes![center_style]}>{ indicator.clone() }</span> }
+    });
+    let children = button
+        .children
+        .iter()
+        .chain(props.children.iter())
+        .chain(center_indicator)
+        .collect();
+    button.children = Children::new(children);
+
+    button.disabled |= props.loading;
+    if props.loading {
+        button.attributes = button.attributes.attr("aria-busy", "true");
+    }
+
+    html! { <Button ..button /> }
+}
@@ -0,0 +1,56 @@
+//! Sheets for the colors of a palette, derived once per theme.
+use std::collections::BTreeMap;
+
+use material_styles_yew::{ColorSpec, Palette, PaletteColor};
+
+/// The sheets of a component for every color of a palette, built when deriving the styles of
+/// a theme, instead of on every render.
+pub(crate) struct PaletteSheets<T> {
+    palette: Vec<(PaletteColor, T)>,
+    custom: BTreeMap<String, T>,
+}
+
+impl<T> PaletteSheets<T> {
+    pub(crate) fn new(palette: &Palette, sheets: impl Fn(&ColorSpec) -> T) -> Self {
+        let colors = [
+            PaletteColor::Primary,
+            PaletteColor::Secondary,
+            PaletteColor::Error,
+            PaletteColor::Warning,
+            PaletteColor::Info,
+            PaletteColor::Success,
+        ];
+        let palette_sheets = colors
+            .iter()
+            .filter_map(|&color| Some((color, sheets(palette.color(color)?))))
+            .collect();
+        let custom = palette
+            .custom
+            .iter()
+            .map(|(key, spec)| (key.clone(), sheets(spec)))
+            .collect();
+        Self {
+            palette: palette_sheets,
+            custom,
+        }
+    }
+
+    /// The sheets of a color. A custom color missing from the palette has none, which fails a
+    /// debug assertion.
+    pub(crate) fn get(&self, color: PaletteColor) -> Option<&T> {
+        let sheets = match color {
+            PaletteColor::Custom(key) => self.custom.get(key),
+            color => self
+                .palette
+                .iter()
+                .find(|(c, _)| *c == color)
+                .map(|(_, sheets)| sheets),
+        };
+        debug_assert!(
+            sheets.is_some(),
+            "the color {:?} is missing from the palette of the theme",
+            color
+        );
+        sheets
+    }
+}
@@ -9,8 +9,11 @@ use yew::function_component;
 use yew::html;
 use yew::Children;
 use yew::Html;
+use yew::NodeRef;
 use yew::Properties;
 
+use crate::attributes::ExtraAttributes;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PaperStyleRoot {
     css_scopes: Sheet,
@@ -56,6 +59,12 @@ pub struct PaperProperties {
     pub variant: PaperVariant,
     #[prop_or_default]
     pub edge_style: PaperEdgeStyle,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
 }
 
 struct DefaultStyles {
@@ -68,11 +77,17 @@ struct DefaultStyles {
 
 fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     // TODO: add color options
-    let root_style = sheet!(
+    let root_colors = sheet!(
         background-color: ${theme.palette.background.paper};
         color: ${theme.palette.text.primary};
-        transition: box-shadow 300ms cubic-bezier(0.4, 0, 0.2, 1) 0ms;
     );
+    let root_transition = theme
+        .motion
+        .transition("box-shadow 300ms cubic-bezier(0.4, 0, 0.2, 1) 0ms");
+    let mut root_style = vec![];
+    root_style.extend_from_slice(&root_colors);
+    root_style.extend_from_slice(&root_transition);
+    let root_style = Sheet::from(root_style);
     let rounded_style = sheet!(
         border-radius: ${&theme.shape.border_radius};
     );
@@ -133,9 +148,9 @@ pub fn Paper(props: &PaperProperties) -> Html {
     let root_style = Sheet::from(root_style);
     let root_style = use_style(/* ""Mwi-paper-root", */ root_style);
 
-    html! {
-        <div class={classes![root_style]}>
+    props.attributes.apply_to(html! {
+        <div ref={&props.node_ref} class={classes![root_style]}>
             { for props.children.iter() }
         </div>
-    }
+    })
 }
@@ -1,15 +1,36 @@
 use material_styles_yew::use_theme;
+use material_styles_yew::Breakpoint;
 use material_styles_yew::Theme;
+use std::cell::RefCell;
 use stylist::ast::ScopeContent;
 use stylist::ast::{sheet, Sheet};
 use stylist::yew::use_style;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
 use yew::classes;
 use yew::function_component;
 use yew::html;
+use yew::use_effect;
+use yew::use_effect_with_deps;
+use yew::use_mut_ref;
+use yew::use_node_ref;
+use yew::use_state;
+use yew::virtual_dom::AttrValue;
+use yew::Callback;
 use yew::Children;
+use yew::FocusEvent;
 use yew::Html;
+use yew::KeyboardEvent;
+use yew::MouseEvent;
+use yew::NodeRef;
 use yew::Properties;
 
+use crate::attributes::ExtraAttributes;
+use crate::bindings::observe_resize;
+use crate::button_base::{ButtonBase, ButtonBaseActions, ButtonPressedEvent, RippleBehaviour};
+use crate::hooks::{use_id, ImperativeRef};
+use crate::paper::Paper;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ToolbarStyleRoot {
     css_scopes: Sheet,
@@ -45,6 +66,23 @@ impl Default for ToolbarGutters {
     }
 }
 
+/// What happens to children that do not fit into the toolbar.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ToolbarOverflow {
+    /// Children that do not fit overflow the toolbar.
+    Visible,
+    /// Children that do not fit move, in order, into a trailing "more" menu. The menu is a
+    /// disclosure: the "more" button expands a panel of the moved children, which keep their own
+    /// roles and are reached with Tab.
+    Menu,
+}
+
+impl Default for ToolbarOverflow {
+    fn default() -> Self {
+        Self::Visible
+    }
+}
+
 #[derive(Default, Clone, PartialEq, Debug, Properties)]
 pub struct ToolbarProperties {
     #[prop_or_default]
@@ -55,6 +93,17 @@ pub struct ToolbarProperties {
     pub variant: ToolbarVariant,
     #[prop_or_default]
     pub gutters: ToolbarGutters,
+    #[prop_or_default]
+    pub overflow: ToolbarOverflow,
+    /// The accessible label of the "more" button, when overflowing into a menu.
+    #[prop_or(AttrValue::Static("More"))]
+    pub overflow_label: AttrValue,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
 }
 
 struct DefaultStyles {
@@ -63,6 +112,16 @@ struct DefaultStyles {
     variant_dense: Sheet,
     variant_regular: Sheet,
     root_override: Sheet,
+    overflow: OverflowStyles,
+}
+
+struct OverflowStyles {
+    container: Sheet,
+    item: Sheet,
+    more: Sheet,
+    more_button: Sheet,
+    menu: Sheet,
+    menu_item: Sheet,
 }
 
 fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
@@ -73,11 +132,15 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     );
     let spacing2 = "16px"; // TODO: put into theme
     let spacing3 = "16px";
-    let small_break = "(min-width:600px)";
+    let small_break = theme.breakpoints.up(Breakpoint::Sm);
+    let landscape_break = format!(
+        "{} and (orientation: landscape)",
+        theme.breakpoints.up(Breakpoint::Xs)
+    );
     let gutters_enabled = sheet!(
         padding-left: ${&spacing2};
         padding-right: ${&spacing2};
-        @media ${small_break} {
+        @media ${&small_break} {
             padding-left: ${&spacing3};
             padding-right: ${&spacing3};
         }
@@ -87,10 +150,10 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
     );
     let variant_regular = sheet!(
         min-height: 56px;
-        @media (min-width:0px) and (orientation: landscape) {
+        @media ${landscape_break} {
             min-height:48px;
         }
-        @media (min-width:600px) {
+        @media ${small_break} {
             min-height:64px;
         }
     );
@@ -102,12 +165,54 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         .cloned()
         .unwrap_or_default();
 
+    let overflow = OverflowStyles {
+        container: sheet!(
+            position: relative;
+            display: flex;
+            align-items: safe center;
+            flex: 1 1 auto;
+            min-width: 0;
+            align-self: stretch;
+        ),
+        item: sheet!(
+            display: flex;
+            flex: none;
+        ),
+        more: sheet!(
+            position: relative;
+            flex: none;
+            margin-left: auto;
+        ),
+        more_button: sheet!(
+            width: 48px;
+            height: 48px;
+            border-radius: 50%;
+            color: inherit;
+            font-size: ${theme.typography.pixels_to_rem(24.0)};
+        ),
+        menu: sheet!(
+            position: absolute;
+            top: 100%;
+            right: 0;
+            z-index: ${theme.z_index.modal};
+            min-width: 112px;
+            padding: 8px 0;
+            display: flex;
+            flex-direction: column;
+        ),
+        menu_item: sheet!(
+            display: flex;
+            padding: 0 8px;
+        ),
+    };
+
     DefaultStyles {
         root_style,
         gutters_enabled,
         variant_dense,
         variant_regular,
         root_override,
+        overflow,
     }
 }
 
@@ -141,9 +246,254 @@ pub fn Toolbar(props: &ToolbarProperties) -> Html {
     let root_style = Sheet::from(root_style);
     let root_style = use_style(/* ""Mwi-toolbar-root", */ root_style);
 
+    let children = match props.overflow {
+        ToolbarOverflow::Visible => html! { <>{ for props.children.iter() }</> },
+        ToolbarOverflow::Menu => html! {
+            <OverflowMenu label={props.overflow_label.clone()}>
+                { for props.children.iter() }
+            </OverflowMenu>
+        },
+    };
+
+    props.attributes.apply_to(html! {
+        <div ref={&props.node_ref} class={classes![root_style]}>
+            { children }
+        </div>
+    })
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct OverflowMenuProps {
+    label: AttrValue,
+    children: Children,
+}
+
+/// The width reserved for the "more" button before it has been measured, in pixels
+const MORE_BUTTON_WIDTH: f64 = 48.;
+/// Marks the wrappers of the children, to find them when measuring
+const ATTR_OVERFLOW_ITEM: &str = "data-overflow-item";
+const FOCUSABLE: &str =
+    "a[href], button:not([disabled]), input, select, textarea, [tabindex]:not([tabindex='-1'])";
+
+/// The number of leading items that fit into the available width. If not all of them fit, space
+/// for the "more" button is reserved.
+fn count_fitting(widths: &[f64], available: f64, more_width: f64) -> usize {
+    if widths.iter().sum::<f64>() <= available {
+        return widths.len();
+    }
+    let mut used = more_width;
+    widths
+        .iter()
+        .take_while(|width| {
+            used += *width;
+            used <= available
+        })
+        .count()
+}
+
+/// Measures the items shown in the toolbar, remembering their widths. Items in the menu keep the
+/// width they were last measured with.
+fn measure_fitting(
+    container: &Element,
+    more: Option<Element>,
+    more_width: &RefCell<f64>,
+    widths: &RefCell<Vec<f64>>,
+) -> usize {
+    if let Some(more) = more {
+        *more_width.borrow_mut() = more.get_bounding_client_rect().width();
+    }
+    let mut widths = widths.borrow_mut();
+    let items = container.children();
+    let shown = (0..items.length())
+        .filter_map(|index| items.item(index))
+        .filter(|item| item.has_attribute(ATTR_OVERFLOW_ITEM));
+    for (width, item) in widths.iter_mut().zip(shown) {
+        *width = item.get_bounding_client_rect().width();
+    }
+    count_fitting(
+        &widths,
+        container.client_width() as f64,
+        *more_width.borrow(),
+    )
+}
+
+/// Lays out the children of a toolbar, moving those that do not fit into a menu. Children are
+/// measured while shown in the toolbar, when the number of children changes all of them are
+/// shown again to measure them. Moving a child between the toolbar and the menu remounts it, so
+/// children should not rely on local state.
+#[function_component]
+fn OverflowMenu(props: &OverflowMenuProps) -> Html {
+    let styles = use_theme(derive_styles_from_theme);
+    let container_style = use_style(
+        /* "Mwi-toolbar-overflow", */ styles.overflow.container.clone(),
+    );
+    let item_style = use_style(/* "Mwi-toolbar-item", */ styles.overflow.item.clone());
+    let more_style = use_style(/* "Mwi-toolbar-more", */ styles.overflow.more.clone());
+    let menu_item_style = use_style(
+        /* "Mwi-toolbar-menu-item", */ styles.overflow.menu_item.clone(),
+    );
+
+    let container = use_node_ref();
+    let more = use_node_ref();
+    let menu = use_node_ref();
+    let menu_id = use_id(None);
+    let button_actions = use_state(ImperativeRef::<ButtonBaseActions>::new);
+    let open = use_state(|| false);
+    // The number of children shown in the toolbar, all of them until measured
+    let fitting = use_state(|| usize::MAX);
+    let fitting_last = use_mut_ref(|| usize::MAX);
+    let widths = use_mut_ref(Vec::new);
+    let more_width = use_mut_ref(|| MORE_BUTTON_WIDTH);
+    let count = props.children.len();
+    let shown = if widths.borrow().len() == count {
+        *fitting
+    } else {
+        // Show all children again to measure them
+        widths.borrow_mut().resize(count, 0.);
+        *fitting_last.borrow_mut() = usize::MAX;
+        usize::MAX
+    };
+
+    let measure = {
+        let container = container.clone();
+        let more = more.clone();
+        let fitting = fitting.clone();
+        move || {
+            if let Some(element) = container.cast::<Element>() {
+                let more = more.cast::<Element>();
+                let count = measure_fitting(&element, more, &more_width, &widths);
+                if fitting_last.replace(count) != count {
+                    fitting.set(count);
+                }
+            }
+        }
+    };
+    {
+        let measure = measure.clone();
+        let container = container.clone();
+        use_effect_with_deps(
+            move |_| {
+                let observer = container
+                    .cast::<Element>()
+                    .and_then(|element| observe_resize(&element, measure));
+                move || drop(observer)
+            },
+            (),
+        );
+    }
+    // Children might have changed their size
+    use_effect(move || {
+        measure();
+        || ()
+    });
+
+    let overflowing = count > shown;
+    {
+        let menu = menu.clone();
+        use_effect_with_deps(
+            move |open| {
+                let first_focusable = menu
+                    .cast::<Element>()
+                    .filter(|_| *open)
+                    .and_then(|menu| menu.query_selector(FOCUSABLE).ok().flatten())
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+                if let Some(element) = first_focusable {
+                    let _ = element.focus();
+                }
+                || ()
+            },
+            *open && overflowing,
+        );
+    }
+
+    let items = props.children.iter().take(shown).map(|child| {
+        html! {
+            <div class={classes![item_style.clone()]} data-overflow-item="">
+                { child }
+            </div>
+        }
+    });
+
+    let toggle = {
+        let open = open.clone();
+        Callback::from(move |_: ButtonPressedEvent| open.set(!*open))
+    };
+    let onkeydown = {
+        let open = open.clone();
+        let button_actions = button_actions.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if *open && event.key() == "Escape" {
+                event.prevent_default();
+                open.set(false);
+                let actions = button_actions.get().as_deref().cloned();
+                if let Some(actions) = actions {
+                    actions.focus_visible();
+                }
+            }
+        })
+    };
+    // Close the menu when focus moves elsewhere
+    let onfocusout = {
+        let open = open.clone();
+        let more = more.clone();
+        Callback::from(move |event: FocusEvent| {
+            let target = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let inside = more
+                .cast::<Node>()
+                .map_or(false, |more| more.contains(target.as_ref()));
+            if !inside {
+                open.set(false);
+            }
+        })
+    };
+    let close = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(false))
+    };
+
+    let menu_html = (*open).then(|| {
+        let attributes = ExtraAttributes::new()
+            .attr("id", menu_id.clone())
+            .on_click(close);
+        let hidden_items = props.children.iter().skip(shown).map(|child| {
+            html! { <div class={classes![menu_item_style.clone()]}>{ child }</div> }
+        });
+        html! {
+            <Paper class={styles.overflow.menu.clone()} node_ref={&menu} {attributes}>
+                { for hidden_items }
+            </Paper>
+        }
+    });
+    let more_html = overflowing.then(|| {
+        let mut attributes = ExtraAttributes::new()
+            .attr("aria-label", props.label.clone())
+            .attr("aria-expanded", if *open { "true" } else { "false" });
+        // The menu is only rendered while open, and must not be referred to otherwise
+        if *open {
+            attributes = attributes.attr("aria-controls", menu_id.clone());
+        }
+        html! {
+            <div ref={&more} class={classes![more_style]} {onkeydown} {onfocusout}>
+                <ButtonBase
+                    class={styles.overflow.more_button.clone()}
+                    ripples={RippleBehaviour::Centered}
+                    on_pressed={toggle}
+                    action={(*button_actions).clone()}
+                    {attributes}
+                >
+                    { "\u{22EE}" }
+                </ButtonBase>
+                { for menu_html }
+            </div>
+        }
+    });
+
     html! {
-        <div class={classes![root_style]}>
-            { for props.children.iter() }
+        <div ref={&container} class={classes![container_style]}>
+            { for items }
+            { for more_html }
         </div>
     }
 }
@@ -0,0 +1,269 @@
+use material_styles_yew::use_theme;
+use material_styles_yew::Theme;
+use stylist::ast::{sheet, Sheet};
+use stylist::yew::use_style;
+use yew::classes;
+use yew::function_component;
+use yew::html;
+use yew::Html;
+use yew::NodeRef;
+use yew::Properties;
+
+use crate::app_bar::{AppBar, AppBarColor, AppBarPosition};
+use crate::attributes::ExtraAttributes;
+use crate::hooks::{use_optional_scroll_trigger, ScrollTarget, ScrollTriggerOptions};
+use crate::toolbar::{Toolbar, ToolbarVariant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopAppBarStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for TopAppBarStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// The layouts of a Material 3 top app bar.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TopAppBarVariant {
+    /// A single row with the title next to the navigation
+    Small,
+    /// A single row with the title centered
+    CenterAligned,
+    /// A medium headline below the row, collapsing into it when scrolling
+    Medium,
+    /// A large headline below the row, collapsing into it when scrolling
+    Large,
+}
+
+impl Default for TopAppBarVariant {
+    fn default() -> Self {
+        Self::Small
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Properties)]
+pub struct TopAppBarProperties {
+    #[prop_or_default]
+    pub class: Sheet,
+    #[prop_or_default]
+    pub variant: TopAppBarVariant,
+    /// The title of the page
+    #[prop_or_default]
+    pub title: Html,
+    /// Leading navigation, usually a menu or back button
+    #[prop_or_default]
+    pub navigation: Html,
+    /// Trailing action buttons
+    #[prop_or_default]
+    pub actions: Html,
+    #[prop_or_default]
+    pub position: AppBarPosition,
+    #[prop_or_default]
+    pub color: AppBarColor,
+    /// The scrolling container under the app bar, elevating it and collapsing its headline.
+    #[prop_or_default]
+    pub scroll_target: ScrollTarget,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+struct DefaultStyles {
+    row: Sheet,
+    navigation: Sheet,
+    actions: Sheet,
+    title: Sheet,
+    title_centered: Sheet,
+    title_hidden: Sheet,
+    headline_medium: Sheet,
+    headline_large: Sheet,
+    headline_collapsed: Sheet,
+    root_override: Sheet,
+}
+
+/// The height of the headline row of the medium and large variants, in pixels
+fn headline_height(variant: TopAppBarVariant) -> f64 {
+    match variant {
+        TopAppBarVariant::Small | TopAppBarVariant::CenterAligned => 0.,
+        TopAppBarVariant::Medium => 48.,
+        TopAppBarVariant::Large => 88.,
+    }
+}
+
+fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
+    let typography = &theme.typography;
+    let fade = theme
+        .motion
+        .transition("opacity 200ms cubic-bezier(0.4, 0, 0.2, 1) 0ms");
+    let collapse = theme.motion.transition(
+        "height 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        padding 250ms cubic-bezier(0.4, 0, 0.2, 1) 0ms, \
+        opacity 200ms cubic-bezier(0.4, 0, 0.2, 1) 0ms",
+    );
+
+    let row = sheet!(
+        min-height: 64px;
+        gap: 4px;
+    );
+    let navigation = sheet!(
+        display: flex;
+        flex: none;
+    );
+    let actions = sheet!(
+        display: flex;
+        flex: none;
+        margin-left: auto;
+    );
+    let mut title = sheet!(
+        flex: 1 1 auto;
+        margin: 0 8px;
+        overflow: hidden;
+        text-overflow: ellipsis;
+        white-space: nowrap;
+        font-size: ${typography.pixels_to_rem(22.0)};
+        line-height: ${typography.pixels_to_rem(28.0)};
+        font-weight: 400;
+        opacity: 1;
+    )
+    .to_vec();
+    title.extend_from_slice(&fade);
+    let title = Sheet::from(title);
+    let title_centered = sheet!(
+        position: absolute;
+        left: 50%;
+        transform: translateX(-50%);
+        max-width: 60%;
+    );
+    let title_hidden = sheet!(
+        opacity: 0;
+    );
+
+    let headline = |font_size: f32, line_height: f32, height: f64, padding: f64| {
+        let mut headline = sheet!(
+            align-items: flex-end;
+            box-sizing: border-box;
+            overflow: hidden;
+            white-space: nowrap;
+            text-overflow: ellipsis;
+            height: ${format!("{}px", height)};
+            padding-bottom: ${format!("{}px", padding)};
+            font-size: ${typography.pixels_to_rem(font_size)};
+            line-height: ${typography.pixels_to_rem(line_height)};
+            font-weight: 400;
+        )
+        .to_vec();
+        headline.extend_from_slice(&collapse);
+        Sheet::from(headline)
+    };
+    let medium = TopAppBarVariant::Medium;
+    let large = TopAppBarVariant::Large;
+    let headline_medium = headline(24.0, 32.0, headline_height(medium), 20.0);
+    let headline_large = headline(28.0, 36.0, headline_height(large), 28.0);
+    let headline_collapsed = sheet!(
+        height: 0;
+        min-height: 0;
+        padding-bottom: 0;
+        opacity: 0;
+    );
+
+    let root_override = theme
+        .components
+        .search_override::<TopAppBarStyleRoot>()
+        .map(|c| &c.css_scopes)
+        .cloned()
+        .unwrap_or_default();
+
+    DefaultStyles {
+        row,
+        navigation,
+        actions,
+        title,
+        title_centered,
+        title_hidden,
+        headline_medium,
+        headline_large,
+        headline_collapsed,
+        root_override,
+    }
+}
+
+/// A Material 3 top app bar, showing the title, navigation and actions of a page.
+///
+/// The app bar is elevated while content is scrolled under it. The headline of the medium and
+/// large variants collapses into the title of the top row when scrolling down.
+#[function_component]
+pub fn TopAppBar(props: &TopAppBarProperties) -> Html {
+    use TopAppBarVariant::*;
+
+    let styles = use_theme(derive_styles_from_theme);
+    let has_headline = matches!(props.variant, Medium | Large);
+    let collapsed = use_optional_scroll_trigger(has_headline.then(|| ScrollTriggerOptions {
+        target: props.scroll_target.clone(),
+        threshold: headline_height(props.variant),
+        disable_hysteresis: true,
+    }));
+
+    let mut root_style = styles.root_override.to_vec();
+    root_style.extend_from_slice(&props.class);
+    let root_style = Sheet::from(root_style);
+
+    let mut title_style = styles.title.to_vec();
+    if props.variant == CenterAligned {
+        title_style.extend_from_slice(&styles.title_centered);
+    }
+    if has_headline && !collapsed {
+        title_style.extend_from_slice(&styles.title_hidden);
+    }
+    let title_style = use_style(/* "Mwi-top-app-bar-title", */ Sheet::from(title_style));
+    let navigation_style = use_style(
+        /* "Mwi-top-app-bar-navigation", */ styles.navigation.clone(),
+    );
+    let actions_style = use_style(/* "Mwi-top-app-bar-actions", */ styles.actions.clone());
+
+    let mut headline_style = match props.variant {
+        Large => styles.headline_large.to_vec(),
+        _ => styles.headline_medium.to_vec(),
+    };
+    if collapsed {
+        headline_style.extend_from_slice(&styles.headline_collapsed);
+    }
+    let headline_style = Sheet::from(headline_style);
+
+    // The title of the top row only repeats the headline, while it is collapsed
+    let title_hidden = has_headline.then(|| "true");
+    let headline = has_headline.then(|| {
+        html! {
+            <Toolbar class={headline_style} variant={ToolbarVariant::Dense}>
+                { props.title.clone() }
+            </Toolbar>
+        }
+    });
+
+    html! {
+        <AppBar
+            class={root_style}
+            position={props.position}
+            color={props.color}
+            elevation={3}
+            elevate_on_scroll={true}
+            scroll_target={props.scroll_target.clone()}
+            attributes={props.attributes.clone()}
+            node_ref={props.node_ref.clone()}
+        >
+            <Toolbar class={styles.row.clone()} variant={ToolbarVariant::Dense}>
+                <div class={classes![navigation_style]}>{ props.navigation.clone() }</div>
+                <div class={classes![title_style]} aria-hidden={title_hidden}>
+                    { props.title.clone() }
+                </div>
+                <div class={classes![actions_style]}>{ props.actions.clone() }</div>
+            </Toolbar>
+            { for headline }
+        </AppBar>
+    }
+}
@@ -0,0 +1,51 @@
+//! Ripples giving visual feedback on interaction, as shown by buttons.
+//!
+//! [`TouchRipple`] can be rendered inside any custom component. The component decides when
+//! ripples start and stop through the [`TouchRippleHandle`] bound to an [`ImperativeRef`].
+//!
+//! ```
+//! use material_yewi::touch_ripple::{
+//!     ImperativeRef, RippleStartOptions, TouchRipple, TouchRippleHandle,
+//! };
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn ClickableCard() -> Html {
+//!     let ripple = use_state(ImperativeRef::<TouchRippleHandle>::new);
+//!     let onmousedown = {
+//!         let ripple = ripple.clone();
+//!         Callback::from(move |ev: MouseEvent| {
+//!             if let Some(handle) = ripple.get().as_deref().cloned() {
+//!                 handle.start(ev, RippleStartOptions::default());
+//!             }
+//!         })
+//!     };
+//!     let onmouseup = {
+//!         let ripple = ripple.clone();
+//!         Callback::from(move |_: MouseEvent| {
+//!             if let Some(handle) = ripple.get().as_deref().cloned() {
+//!                 handle.stop();
+//!             }
+//!         })
+//!     };
+//!     html! {
+//!         <div style="position: relative; overflow: hidden;" {onmousedown} {onmouseup}>
+//!             {"Click me"}
+//!             <TouchRipple handle={(*ripple).clone()} />
+//!         </div>
+//!     }
+//! }
+//! ```
+mod ripple;
+#[allow(clippy::module_inception)]
+mod touch_ripple;
+
+pub use crate::hooks::ImperativeRef;
+pub use ripple::{
+    RippleConfig, RippleStyleRoot, CLASS_CHILD, CLASS_CHILD_LEAVING, CLASS_CHILD_PULSATE,
+    CLASS_PULSATE, CLASS_RIPPLE, CLASS_VISIBLE,
+};
+pub use touch_ripple::{
+    RippleStartEvent, RippleStartOptions, TouchRipple, TouchRippleHandle, TouchRippleProps,
+    TouchRippleStyleRoot,
+};
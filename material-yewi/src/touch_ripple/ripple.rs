@@ -0,0 +1,222 @@
+use material_styles_yew::{use_theme, CssColor, Motion, Theme};
+use stylist::ast::{sheet, Sheet};
+use stylist::yew::use_style;
+use yew::function_component;
+use yew::html;
+use yew::Html;
+use yew::Properties;
+
+use super::touch_ripple::{KEYFRAMES_ENTER, KEYFRAMES_EXIT, KEYFRAMES_PULSATE};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RippleStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for RippleStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+/// Configures the appearance of ripples. Install it into a theme with
+/// `theme.components.add_override(RippleConfig { .. })`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RippleConfig {
+    /// The color of the ripple. Defaults to the text color of the button.
+    pub color: Option<CssColor>,
+    /// The opacity of a fully visible ripple.
+    pub opacity: f32,
+    /// The duration of a ripple growing and fading out, in milliseconds.
+    pub duration_ms: u32,
+    /// The duration of the pulsating ripple growing, in milliseconds.
+    pub pulsate_duration_ms: u32,
+    /// The period of one pulse of the pulsating ripple, in milliseconds.
+    pub pulsate_period_ms: u32,
+    /// The delay before a touch starts a ripple, in milliseconds. Touches that move during the
+    /// delay, e.g. to scroll, do not show a ripple. Quick taps show their ripple for at least
+    /// this duration.
+    pub touch_delay_ms: u32,
+}
+
+impl Default for RippleConfig {
+    fn default() -> Self {
+        Self {
+            color: None,
+            opacity: 0.3,
+            duration_ms: 550,
+            pulsate_duration_ms: 200,
+            pulsate_period_ms: 2500,
+            touch_delay_ms: 80,
+        }
+    }
+}
+
+impl RippleConfig {
+    pub(crate) fn from_theme(theme: &Theme) -> Self {
+        theme
+            .components
+            .search_override::<Self>()
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+pub const CLASS_RIPPLE: &str = "ripple";
+pub const CLASS_VISIBLE: &str = "rippleVisible";
+pub const CLASS_PULSATE: &str = "ripplePulsate";
+pub const CLASS_CHILD: &str = "child";
+pub const CLASS_CHILD_LEAVING: &str = "childLeaving";
+pub const CLASS_CHILD_PULSATE: &str = "childPulsate";
+
+struct ThemeStyles {
+    root: Sheet,
+}
+
+fn derive_ripple_styles_from_theme(theme: &Theme) -> ThemeStyles {
+    // FIXME: push into theme
+    let ease_in_out = "cubic-bezier(0.4, 0, 0.2, 1)";
+    let config = RippleConfig::from_theme(theme);
+    let duration = format!("{}ms", config.duration_ms);
+    let duration_shorter = format!("{}ms", config.pulsate_duration_ms);
+    let duration_slow = format!("{}ms", config.pulsate_period_ms);
+    let color = config
+        .color
+        .map(|c| c.to_css_value())
+        .unwrap_or_else(|| "currentColor".to_string());
+    let keyframes_enter = KEYFRAMES_ENTER.name();
+    let keyframes_exit = KEYFRAMES_EXIT.name();
+    let keyframes_pulsate = KEYFRAMES_PULSATE.name();
+
+    let root_default = sheet!(
+        opacity: 0;
+        position: absolute;
+
+        &.${CLASS_VISIBLE} {
+            opacity: ${config.opacity};
+            transform: scale(1);
+            animation-name: ${keyframes_enter};
+            animation-duration: ${duration};
+            animation-timing-function: ${ease_in_out};
+        }
+
+        &.${CLASS_PULSATE} {
+            animation-duration: ${duration_shorter};
+        }
+
+        &${" "}*.${CLASS_CHILD} {
+            opacity: 1;
+            display: block;
+            width: 100%;
+            height: 100%;
+            border-radius: 50%;
+            background-color: ${color};
+        }
+
+        &${" "}*.${CLASS_CHILD_LEAVING} {
+            opacity: 0;
+            animation-name: ${keyframes_exit};
+            animation-duration: ${duration};
+            animation-timing-function: ${ease_in_out};
+        }
+
+        &${" "}*.${CLASS_CHILD_PULSATE} {
+            position: absolute;
+            left: 0px;
+            top: 0;
+            animation-name: ${keyframes_pulsate};
+            animation-duration: ${duration_slow};
+            animation-timing-function: ${ease_in_out};
+            animation-iteration-count: infinite;
+            animation-delay: ${duration_shorter};
+        }
+    );
+    // Without motion, ripples neither grow nor pulsate
+    let root_motion = match theme.motion {
+        Motion::Full => Sheet::default(),
+        Motion::System => sheet!(
+            @media (prefers-reduced-motion: reduce) {
+                &.${CLASS_VISIBLE} {
+                    animation-name: none;
+                }
+                &${" "}*.${CLASS_CHILD_PULSATE} {
+                    animation-name: none;
+                }
+            }
+        ),
+        Motion::Reduced => sheet!(
+            &.${CLASS_VISIBLE} {
+                animation-name: none;
+            }
+            &${" "}*.${CLASS_CHILD_PULSATE} {
+                animation-name: none;
+            }
+        ),
+        Motion::None => sheet!(
+            &.${CLASS_VISIBLE} {
+                animation-name: none;
+            }
+            &${" "}*.${CLASS_CHILD_PULSATE} {
+                animation-name: none;
+            }
+            &${" "}*.${CLASS_CHILD_LEAVING} {
+                animation-name: none;
+            }
+        ),
+    };
+
+    let root_override = theme
+        .components
+        .search_override::<RippleStyleRoot>()
+        .map(|c| c.css_scopes.clone())
+        .unwrap_or_default();
+
+    let mut root = vec![];
+    root.extend_from_slice(&root_default);
+    root.extend_from_slice(&root_motion);
+    root.extend_from_slice(&root_override);
+    let root = Sheet::from(root);
+
+    ThemeStyles { root }
+}
+
+#[derive(PartialEq, Properties, Debug, Clone)]
+pub struct RippleProps {
+    // position/size information
+    pub pos_x: f64,
+    pub pos_y: f64,
+    pub pos_size: f64,
+    // tell the component if it is supposed to be visible
+    pub is_leaving: bool,
+    pub is_pulsating: bool,
+}
+
+#[function_component]
+pub fn Ripple(props: &RippleProps) -> Html {
+    let styles = use_theme(derive_ripple_styles_from_theme);
+    let root_class = use_style(/* "Mwi-ripple", */ styles.root.clone());
+
+    let additional_style = format!(
+        "width: {sz}px; height: {sz}px; top: {top}px; left: {left}px;",
+        sz = props.pos_size,
+        top = -(props.pos_size / 2.0) + props.pos_y,
+        left = -(props.pos_size / 2.0) + props.pos_x,
+    );
+    let ripple_classes = yew::classes![
+        root_class,
+        CLASS_RIPPLE,
+        CLASS_VISIBLE,
+        props.is_pulsating.then(|| CLASS_PULSATE),
+    ];
+    let child_classes = yew::classes![
+        CLASS_CHILD,
+        props.is_pulsating.then(|| CLASS_CHILD_PULSATE),
+        props.is_leaving.then(|| CLASS_CHILD_LEAVING),
+    ];
+
+    html! {
+        <span class={ripple_classes} style={additional_style}>
+            <span class={child_classes} />
+        </span>
+    }
+}
@@ -0,0 +1,444 @@
+use std::rc::Rc;
+
+use gloo::timers::callback::Timeout;
+use lazy_static::lazy_static;
+use material_styles_yew::use_theme;
+use material_styles_yew::Theme;
+use material_styles_yew::{use_keyframes, Keyframes};
+use stylist::ast::{sheet, Sheet};
+use stylist::yew::use_style;
+use web_sys::Element;
+use yew::classes;
+use yew::function_component;
+use yew::use_effect_with_deps;
+use yew::use_mut_ref;
+use yew::use_node_ref;
+use yew::use_reducer;
+use yew::Callback;
+use yew::Html;
+use yew::KeyboardEvent;
+use yew::MouseEvent;
+use yew::NodeRef;
+use yew::Properties;
+use yew::Reducible;
+use yew::TouchEvent;
+use yew::{html, html_nested};
+
+use super::ripple::{Ripple, RippleConfig, RippleProps};
+use crate::hooks::{bind_imperative_ref, ImperativeRef};
+
+lazy_static! {
+    pub(crate) static ref KEYFRAMES_ENTER: Keyframes = Keyframes::new("mwi-ripple-enter", |name| {
+        sheet!(
+            "@keyframes ${name} {
+                0% {
+                    transform: scale(0);
+                    opacity: 0.1;
+                }
+                100% {
+                    transform: scale(1);
+                }
+            }",
+            name = name
+        )
+    });
+    pub(crate) static ref KEYFRAMES_EXIT: Keyframes = Keyframes::new("mwi-ripple-exit", |name| {
+        sheet!(
+            "@keyframes ${name} {
+                0% {
+                    opacity: 1;
+                }
+                100% {
+                    opacity: 0;
+                }
+            }",
+            name = name
+        )
+    });
+    pub(crate) static ref KEYFRAMES_PULSATE: Keyframes =
+        Keyframes::new("mwi-ripple-pulsate", |name| {
+            sheet!(
+                "@keyframes ${name} {
+                    0% {
+                        transform: scale(1);
+                    }
+                    50% {
+                        transform: scale(0.92);
+                    }
+                    100% {
+                        transform: scale(1);
+                    }
+                }",
+                name = name
+            )
+        });
+}
+
+/// How long after a touch ended a mouse down is taken to be emulated for the touch
+const IGNORE_MOUSE_DOWN_MS: u32 = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TouchRippleStyleRoot {
+    css_scopes: Sheet,
+}
+
+impl From<Sheet> for TouchRippleStyleRoot {
+    fn from(scopes: Sheet) -> Self {
+        Self { css_scopes: scopes }
+    }
+}
+
+struct ThemeStyles {
+    root: Sheet,
+    duration_ms: u32,
+    touch_delay_ms: u32,
+}
+
+fn derive_styles_from_theme(theme: &Theme) -> ThemeStyles {
+    let root_default = sheet!(
+        overflow: hidden;
+        pointer-events: none;
+        position: absolute;
+        z-index: 0;
+        top: 0;
+        right: 0;
+        bottom: 0;
+        left: 0;
+        border-radius: inherit;
+    );
+
+    let root_override = theme
+        .components
+        .search_override::<TouchRippleStyleRoot>()
+        .map(|c| c.css_scopes.clone())
+        .unwrap_or_default();
+
+    let mut root = vec![];
+    root.extend_from_slice(&root_default);
+    root.extend_from_slice(&root_override);
+    let root = Sheet::from(root);
+
+    let config = RippleConfig::from_theme(theme);
+    ThemeStyles {
+        root,
+        duration_ms: config.duration_ms,
+        touch_delay_ms: config.touch_delay_ms,
+    }
+}
+
+/// The event starting a ripple, determining the position the ripple grows from.
+#[derive(Debug)]
+pub enum RippleStartEvent {
+    /// Grow from the position of the mouse
+    Mouse(MouseEvent),
+    /// Grow from the position of the first touch
+    Touch(TouchEvent),
+    /// Grow from the center, e.g. for keyboard interaction
+    Centered,
+}
+
+impl From<MouseEvent> for RippleStartEvent {
+    fn from(ev: MouseEvent) -> Self {
+        Self::Mouse(ev)
+    }
+}
+
+impl From<TouchEvent> for RippleStartEvent {
+    fn from(ev: TouchEvent) -> Self {
+        Self::Touch(ev)
+    }
+}
+
+impl From<KeyboardEvent> for RippleStartEvent {
+    fn from(_: KeyboardEvent) -> Self {
+        Self::Centered
+    }
+}
+
+/// Options for starting a single ripple.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct RippleStartOptions {
+    /// Grow the ripple from the center, regardless of the starting event.
+    pub center: bool,
+    /// Keep the ripple pulsating until it is stopped. Pulsating ripples are always centered.
+    pub pulsate: bool,
+}
+
+impl RippleStartEvent {
+    fn to_params(&self, node: &NodeRef, options: RippleStartOptions) -> RippleProps {
+        let element = node.cast::<Element>();
+        let rect = element.as_ref().map(|e| e.get_bounding_client_rect());
+        let (_x, _y, width, height, left, top) = match rect {
+            Some(ref r) => (r.x(), r.y(), r.width(), r.height(), r.left(), r.top()),
+            None => (0., 0., 0., 0., 0., 0.),
+        };
+        let pulsate = options.pulsate;
+        let center = options.center || pulsate || matches!(self, Self::Centered);
+
+        let (pos_x, pos_y) = match self {
+            Self::Touch(ref ev_touch) if !center => {
+                let first_touch = ev_touch.touches().get(0).unwrap();
+                (
+                    first_touch.client_x() as f64 - left,
+                    first_touch.client_y() as f64 - top,
+                )
+            }
+            Self::Mouse(ref ev_press)
+                if !center && (ev_press.client_x() != 0 || ev_press.client_y() != 0) =>
+            {
+                (
+                    ev_press.client_x() as f64 - left,
+                    ev_press.client_y() as f64 - top,
+                )
+            }
+            _ => ((width / 2.).round(), (height / 2.).round()),
+        };
+        let pos_size = if center {
+            ((2. * width * width + height * height) / 3.).sqrt()
+        } else {
+            let (client_x, client_y) = match element {
+                Some(ref e) => (e.client_width(), e.client_height()),
+                None => (0, 0),
+            };
+            let size_x = 2. + 2. * pos_x.max((client_x as f64 - pos_x).abs());
+            let size_y = 2. + 2. * pos_y.max((client_y as f64 - pos_y).abs());
+            (size_x * size_x + size_y * size_y).sqrt()
+        };
+
+        yew::props! {RippleProps {
+            pos_x,
+            pos_y,
+            pos_size,
+            is_pulsating: pulsate,
+            is_leaving: false,
+        }}
+    }
+}
+
+/// Controls the ripples of a rendered [`TouchRipple`].
+///
+/// Clone the handle out of the [`ImperativeRef`] before invoking it, since starting and
+/// stopping ripples rerenders the [`TouchRipple`], which binds the handle anew.
+#[derive(Clone)]
+pub struct TouchRippleHandle {
+    start: Callback<(RippleStartEvent, RippleStartOptions)>,
+    stop: Callback<bool>,
+}
+
+impl TouchRippleHandle {
+    /// Start a new ripple. The ripple stays visible until it is stopped.
+    ///
+    /// Ripples started by a touch are delayed by [`RippleConfig::touch_delay_ms`], so that
+    /// touches starting to scroll can stop them before they are shown.
+    pub fn start(&self, event: impl Into<RippleStartEvent>, options: RippleStartOptions) {
+        self.start.emit((event.into(), options));
+    }
+    /// Start a centered, pulsating ripple, as is done to indicate keyboard focus.
+    pub fn pulsate(&self) {
+        let options = RippleStartOptions {
+            center: true,
+            pulsate: true,
+        };
+        self.start(RippleStartEvent::Centered, options);
+    }
+    /// Stop the oldest visible ripple, letting it fade out. A delayed touch ripple that has
+    /// not been shown yet is discarded, e.g. when the touch moves or the pointer leaves.
+    pub fn stop(&self) {
+        self.stop.emit(false);
+    }
+    /// Stop the ripple of a touch that ended. If the touch was too quick for its delayed ripple
+    /// to be shown, the ripple is shown anyway and stopped after the touch delay.
+    pub fn end_touch(&self) {
+        self.stop.emit(true);
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct TouchRippleProps {
+    /// Bound to the handle used to start and stop ripples
+    pub handle: ImperativeRef<TouchRippleHandle>,
+    /// Start all ripples from the center, instead of the position of the pointer
+    #[prop_or(false)]
+    pub center: bool,
+}
+
+/// Renders ripples, giving visual feedback on interaction with its parent element.
+///
+/// The ripples cover the closest positioned ancestor, which should clip its overflow.
+/// Start and stop ripples through the [`TouchRippleHandle`] bound to `handle`.
+#[function_component]
+pub fn TouchRipple(props: &TouchRippleProps) -> Html {
+    type RippleEntry = (u32, RippleProps);
+
+    let id_counter = use_mut_ref(|| 0u32);
+    let container = use_node_ref();
+    #[derive(Default, Clone)]
+    struct RippleState(Vec<RippleEntry>);
+    impl Reducible for RippleState {
+        type Action = Box<dyn FnOnce(&mut Vec<RippleEntry>)>;
+
+        fn reduce(mut self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+            let new_state = Rc::make_mut(&mut self);
+            action(&mut new_state.0);
+            self
+        }
+    }
+    let ripples = use_reducer(RippleState::default);
+    use_keyframes(&KEYFRAMES_ENTER);
+    use_keyframes(&KEYFRAMES_EXIT);
+    use_keyframes(&KEYFRAMES_PULSATE);
+    let themed = use_theme(derive_styles_from_theme);
+    let center = props.center;
+    let duration_ms = themed.duration_ms;
+    let touch_delay_ms = themed.touch_delay_ms;
+
+    let add_ripple: Rc<dyn Fn(RippleProps)> = {
+        let ripples = ripples.clone();
+        Rc::new(move |ripple_params| {
+            let next_id = {
+                let mut c = id_counter.borrow_mut();
+                *c += 1;
+                *c
+            };
+            ripples.dispatch(Box::new(move |v| v.push((next_id, ripple_params))));
+        })
+    };
+    let stop_ripple: Rc<dyn Fn()> = {
+        let ripples = ripples.clone();
+        Rc::new(move || {
+            let ripples_capture = ripples.clone();
+            ripples.dispatch(Box::new(move |v| {
+                let leaving_ripple = match v.iter_mut().find(|v| !v.1.is_leaving) {
+                    None => return,
+                    Some(r) => r,
+                };
+                leaving_ripple.1.is_leaving = true;
+                let ripple_id = leaving_ripple.0;
+                Timeout::new(duration_ms, move || {
+                    ripples_capture.dispatch(Box::new(move |v| {
+                        if let Some(p) = v.iter().position(|v| v.0 == ripple_id) {
+                            v.remove(p);
+                        }
+                    }));
+                })
+                .forget();
+            }));
+        })
+    };
+    // A touch ripple waiting for the touch delay to pass
+    let pending_touch = use_mut_ref(|| Option::<(Timeout, RippleProps)>::None);
+    // The delayed stops of quick taps, by id, cancelled when unmounting
+    let quick_tap_stops = use_mut_ref(|| (0u32, Vec::<(u32, Timeout)>::new()));
+    // Touches are followed by emulated mouse events, which should not start another ripple. The
+    // mouse down is ignored until shortly after the touch ended, since a touch ending without a
+    // click, e.g. when cancelled, is not followed by one.
+    let ignoring_mouse_down = use_mut_ref(|| false);
+    let stop_ignoring = use_mut_ref(|| Option::<Timeout>::None);
+    {
+        let pending_touch = pending_touch.clone();
+        let quick_tap_stops = quick_tap_stops.clone();
+        let stop_ignoring = stop_ignoring.clone();
+        use_effect_with_deps(
+            move |_| {
+                move || {
+                    // dropping the timers cancels them
+                    pending_touch.borrow_mut().take();
+                    quick_tap_stops.borrow_mut().1.clear();
+                    stop_ignoring.borrow_mut().take();
+                }
+            },
+            (),
+        );
+    }
+
+    let handles = TouchRippleHandle {
+        start: {
+            let container = container.clone();
+            let add_ripple = add_ripple.clone();
+            let pending_touch = pending_touch.clone();
+            let ignoring_mouse_down = ignoring_mouse_down.clone();
+            let stop_ignoring = stop_ignoring.clone();
+            Callback::from(
+                move |(event, options): (RippleStartEvent, RippleStartOptions)| {
+                    match event {
+                        RippleStartEvent::Mouse(_) if ignoring_mouse_down.replace(false) => return,
+                        RippleStartEvent::Touch(_) => {
+                            *ignoring_mouse_down.borrow_mut() = true;
+                            stop_ignoring.borrow_mut().take();
+                        }
+                        _ => {}
+                    }
+                    let options = RippleStartOptions {
+                        center: center || options.center,
+                        ..options
+                    };
+                    let ripple_params = event.to_params(&container, options);
+                    if !matches!(event, RippleStartEvent::Touch(_)) || options.pulsate {
+                        add_ripple(ripple_params);
+                        return;
+                    }
+                    let add_ripple = add_ripple.clone();
+                    let pending_capture = pending_touch.clone();
+                    let timer = Timeout::new(touch_delay_ms, move || {
+                        let pending = pending_capture.borrow_mut().take();
+                        if let Some((timer, ripple_params)) = pending {
+                            // the timer is running right now, it must not be cancelled
+                            timer.forget();
+                            add_ripple(ripple_params);
+                        }
+                    });
+                    *pending_touch.borrow_mut() = Some((timer, ripple_params));
+                },
+            )
+        },
+        stop: Callback::from(move |end_touch: bool| {
+            if *ignoring_mouse_down.borrow() {
+                let ignoring_mouse_down = ignoring_mouse_down.clone();
+                let timer = Timeout::new(IGNORE_MOUSE_DOWN_MS, move || {
+                    *ignoring_mouse_down.borrow_mut() = false;
+                });
+                *stop_ignoring.borrow_mut() = Some(timer);
+            }
+            // dropping the timer prevents the delayed ripple from starting
+            let pending = pending_touch.borrow_mut().take();
+            match pending {
+                Some((_, ripple_params)) if end_touch => {
+                    add_ripple(ripple_params);
+                    let stop_ripple = stop_ripple.clone();
+                    let stops_capture = quick_tap_stops.clone();
+                    let mut stops = quick_tap_stops.borrow_mut();
+                    stops.0 += 1;
+                    let id = stops.0;
+                    let timer = Timeout::new(touch_delay_ms, move || {
+                        let mut stops = stops_capture.borrow_mut();
+                        if let Some(p) = stops.1.iter().position(|(i, _)| *i == id) {
+                            // the timer is running right now, it must not be cancelled
+                            stops.1.remove(p).1.forget();
+                        }
+                        drop(stops);
+                        stop_ripple();
+                    });
+                    stops.1.push((id, timer));
+                }
+                // the ripple was never shown, there is nothing to stop
+                Some(_) => {}
+                None => stop_ripple(),
+            }
+        }),
+    };
+    bind_imperative_ref(&props.handle, handles);
+
+    let style = use_style(/* "Mwi-ripple-host", */ themed.root.clone());
+
+    html! {
+        <span class={classes![style]} ref={&container}>
+            {
+                for ripples.0.iter().map(|(k, r)|
+                    html_nested! {
+                        <Ripple key={*k} ..r.clone() />
+                    }
+                )
+            }
+        </span>
+    }
+}
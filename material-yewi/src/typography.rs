@@ -7,8 +7,11 @@ use yew::function_component;
 use yew::html;
 use yew::Children;
 use yew::Html;
+use yew::NodeRef;
 use yew::Properties;
 
+use crate::attributes::ExtraAttributes;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypographyStyleRoot {
     css_scopes: Sheet,
@@ -78,6 +81,54 @@ impl Default for TypographyAlign {
     }
 }
 
+/// Controls where the browser is allowed to break lines inside words.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TypographyWrap {
+    /// Only break at the usual break opportunities, such as spaces.
+    Normal,
+    /// Break inside words only when they would otherwise overflow the line.
+    BreakWord,
+    /// Like [`TypographyWrap::BreakWord`], but breaks are also considered when computing the
+    /// min-content size of the element.
+    Anywhere,
+    /// Break between any two characters, regardless of word boundaries.
+    BreakAll,
+    /// Break overflowing words, inserting hyphens where the language allows it.
+    /// Set a `lang` on the element or an ancestor for best results.
+    Hyphenate,
+}
+
+impl Default for TypographyWrap {
+    fn default() -> Self {
+        TypographyWrap::Normal
+    }
+}
+
+/// Css clamping the text of an element to at most `lines` lines, ending it with an ellipsis.
+/// Clamping to zero lines disables the clamping.
+///
+/// ```
+/// # use material_yewi::typography::line_clamp;
+/// # use stylist::ast::Sheet;
+/// assert_eq!(line_clamp(0), Sheet::default());
+/// assert_ne!(line_clamp(2), Sheet::default());
+/// assert_ne!(line_clamp(2), line_clamp(3));
+/// ```
+pub fn line_clamp(lines: u32) -> Sheet {
+    if lines == 0 {
+        return Default::default();
+    }
+    sheet!(
+        display: ${"-webkit-box"};
+        -webkit-box-orient: vertical;
+        -webkit-line-clamp: ${lines};
+        line-clamp: ${lines};
+        overflow: hidden;
+        text-overflow: ellipsis;
+        white-space: normal;
+    )
+}
+
 #[derive(Default, Clone, PartialEq, Debug, Properties)]
 pub struct TypographyProperties {
     #[prop_or_default]
@@ -92,6 +143,20 @@ pub struct TypographyProperties {
     pub no_wrap: bool,
     #[prop_or(false)]
     pub gutter_bottom: bool,
+    /// Clamp the text to the given number of lines, ending it with an ellipsis.
+    /// Takes precedence over `no_wrap`.
+    #[prop_or_default]
+    pub line_clamp: Option<u32>,
+    /// Where lines may break inside words, e.g. to wrap long urls.
+    /// Has no effect together with `no_wrap`.
+    #[prop_or_default]
+    pub wrap: TypographyWrap,
+    /// Additional attributes and listeners for the root element.
+    #[prop_or_default]
+    pub attributes: ExtraAttributes,
+    /// Bound to the root element rendered by the component.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
     // TODO: color!
 }
 
@@ -119,6 +184,10 @@ struct DefaultStyles {
     align_justify: Sheet, // Applied if align = Justify
     gutter_bottom: Sheet, // Applied if gutter_bottom
     no_wrap: Sheet,       // Applied if no_wrap
+    break_word: Sheet,    // Applied if wrap = BreakWord
+    anywhere: Sheet,      // Applied if wrap = Anywhere
+    break_all: Sheet,     // Applied if wrap = BreakAll
+    hyphenate: Sheet,     // Applied if wrap = Hyphenate
 
     root_override: Sheet, // Overrides applied to root element
 }
@@ -156,6 +225,17 @@ impl DefaultStyles {
             Inherit => Default::default(),
         }
     }
+
+    fn wrap_scopes(&self, wrap: TypographyWrap) -> Sheet {
+        use TypographyWrap::*;
+        match wrap {
+            Normal => Default::default(),
+            BreakWord => self.break_word.clone(),
+            Anywhere => self.anywhere.clone(),
+            BreakAll => self.break_all.clone(),
+            Hyphenate => self.hyphenate.clone(),
+        }
+    }
 }
 
 fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
@@ -171,6 +251,17 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         text-overflow: ellipsis;
         white-space: nowrap;
     );
+    let break_word = sheet!(
+        overflow-wrap: break-word;
+        word-break: break-word;
+    );
+    let anywhere = sheet!(overflow-wrap: anywhere;);
+    let break_all = sheet!(word-break: break-all;);
+    let hyphenate = sheet!(
+        overflow-wrap: break-word;
+        -webkit-hyphens: auto;
+        hyphens: auto;
+    );
     let paragraph = sheet!(margin-bottom: ${"0.35em"};);
     let gutter_bottom = sheet!(margin-bottom: ${"0.35em"};);
     let align_left = sheet!(text-align: left;);
@@ -223,6 +314,10 @@ fn derive_styles_from_theme(theme: &Theme) -> DefaultStyles {
         align_center,
         align_justify,
         no_wrap,
+        break_word,
+        anywhere,
+        break_all,
+        hyphenate,
         gutter_bottom,
         root_override,
     }
@@ -245,6 +340,8 @@ pub fn Typography(props: &TypographyProperties) -> Html {
     } else {
         Default::default()
     };
+    let wrap_sheet = styles.wrap_scopes(props.wrap);
+    let clamp_sheet = props.line_clamp.map(line_clamp).unwrap_or_default();
     let align_sheet = styles.align_scopes(props.align);
 
     let mut root_styles = vec![];
@@ -253,6 +350,8 @@ pub fn Typography(props: &TypographyProperties) -> Html {
     root_styles.extend_from_slice(&variant_sheet);
     root_styles.extend_from_slice(&gutter_sheet);
     root_styles.extend_from_slice(&no_wrap_sheet);
+    root_styles.extend_from_slice(&wrap_sheet);
+    root_styles.extend_from_slice(&clamp_sheet);
     root_styles.extend_from_slice(&align_sheet);
     root_styles.extend_from_slice(&styles.root_override);
     root_styles.extend_from_slice(&props.class);
@@ -260,9 +359,9 @@ pub fn Typography(props: &TypographyProperties) -> Html {
 
     let root_style = use_style(/* "Mwi-typography-root", */ root_styles);
 
-    html! {
-        <@{component} class={classes![root_style]}>
+    props.attributes.apply_to(html! {
+        <@{component} ref={&props.node_ref} class={classes![root_style]}>
             { for props.children.iter() }
         </@>
-    }
+    })
 }
@@ -1,3 +0,0 @@
-pub mod imperative_ref;
-pub mod use_focus_visible;
-pub mod use_state_ext;
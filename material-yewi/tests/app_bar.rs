@@ -0,0 +1,152 @@
+#![cfg(target_arch = "wasm32")]
+
+use material_styles_yew::{CssColor, PaletteMode, Theme, ThemeContents, ThemeProvider};
+use material_yewi::app_bar::{AppBar, AppBarColor};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::{function_component, html, Html, Properties};
+
+mod common;
+
+use common::{computed, make_scrollable, render, scroll_window_to};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(PartialEq, Properties)]
+struct HarnessProps {
+    color: AppBarColor,
+    elevation: u8,
+}
+
+#[function_component]
+fn Harness(props: &HarnessProps) -> Html {
+    html! {
+        <AppBar color={props.color} elevation={props.elevation}>{"Title"}</AppBar>
+    }
+}
+
+async fn render_app_bar(color: AppBarColor, elevation: u8) -> Element {
+    render::<Harness>(HarnessProps { color, elevation }).await
+}
+
+#[wasm_bindgen_test]
+async fn explicit_background_color() {
+    let app_bar = render_app_bar(AppBarColor::Background(CssColor::rgb(0, 128, 0)), 4).await;
+    assert_eq!(computed(&app_bar, "background-color"), "rgb(0, 128, 0)");
+    // white contrasts enough with a dark green
+    assert_eq!(computed(&app_bar, "color"), "rgb(255, 255, 255)");
+
+    let light = render_app_bar(AppBarColor::Background(CssColor::rgb(224, 224, 224)), 4).await;
+    assert_eq!(computed(&light, "color"), "rgba(0, 0, 0, 0.87)");
+}
+
+#[wasm_bindgen_test]
+async fn error_palette_color() {
+    let app_bar = render_app_bar(AppBarColor::Error, 4).await;
+    // #f44336
+    assert_eq!(computed(&app_bar, "background-color"), "rgb(244, 67, 54)");
+}
+
+#[wasm_bindgen_test]
+async fn elevation_zero_has_no_shadow() {
+    let app_bar = render_app_bar(AppBarColor::Primary, 0).await;
+    assert_eq!(computed(&app_bar, "box-shadow"), "none");
+    let elevated = render_app_bar(AppBarColor::Primary, 4).await;
+    assert_ne!(computed(&elevated, "box-shadow"), "none");
+}
+
+#[derive(PartialEq, Properties)]
+struct DarkHarnessProps {
+    enable_color_on_dark: bool,
+}
+
+#[function_component]
+fn DarkHarness(props: &DarkHarnessProps) -> Html {
+    let mut contents = ThemeContents::default();
+    contents.palette.mode = PaletteMode::Dark;
+    contents.palette.background.paper = CssColor::rgb(18, 18, 18);
+    contents.palette.text.primary = CssColor::rgb(255, 255, 255);
+    html! {
+        <ThemeProvider context={Theme::from(contents)}>
+            <AppBar enable_color_on_dark={props.enable_color_on_dark}>{"Title"}</AppBar>
+        </ThemeProvider>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn dark_mode_keeps_the_paper_colors() {
+    let app_bar = render::<DarkHarness>(DarkHarnessProps {
+        enable_color_on_dark: false,
+    })
+    .await;
+    assert_eq!(computed(&app_bar, "background-color"), "rgb(18, 18, 18)");
+    assert_eq!(computed(&app_bar, "color"), "rgb(255, 255, 255)");
+}
+
+#[wasm_bindgen_test]
+async fn dark_mode_with_color_on_dark() {
+    let app_bar = render::<DarkHarness>(DarkHarnessProps {
+        enable_color_on_dark: true,
+    })
+    .await;
+    // #3f51b5
+    assert_eq!(computed(&app_bar, "background-color"), "rgb(63, 81, 181)");
+    assert_eq!(computed(&app_bar, "color"), "rgb(255, 255, 255)");
+}
+
+#[derive(PartialEq, Properties)]
+struct ScrollHarnessProps {
+    hide_on_scroll: bool,
+    elevate_on_scroll: bool,
+}
+
+#[function_component]
+fn ScrollHarness(props: &ScrollHarnessProps) -> Html {
+    html! {
+        <AppBar
+            hide_on_scroll={props.hide_on_scroll}
+            elevate_on_scroll={props.elevate_on_scroll}
+        >
+            {"Title"}
+        </AppBar>
+    }
+}
+
+async fn render_scrolling(hide_on_scroll: bool, elevate_on_scroll: bool) -> Element {
+    render::<ScrollHarness>(ScrollHarnessProps {
+        hide_on_scroll,
+        elevate_on_scroll,
+    })
+    .await
+}
+
+#[wasm_bindgen_test]
+async fn hide_on_scroll_slides_out_of_view() {
+    let _spacer = make_scrollable();
+    let app_bar = render_scrolling(true, false).await;
+    assert_eq!(computed(&app_bar, "transform"), "none");
+
+    scroll_window_to(300.).await;
+    let height = app_bar.get_bounding_client_rect().height();
+    assert_eq!(
+        computed(&app_bar, "transform"),
+        format!("matrix(1, 0, 0, 1, 0, -{})", height)
+    );
+
+    // scrolling back up shows the app bar again
+    scroll_window_to(250.).await;
+    assert_eq!(computed(&app_bar, "transform"), "none");
+}
+
+#[wasm_bindgen_test]
+async fn elevate_on_scroll_raises_once_scrolled() {
+    let _spacer = make_scrollable();
+    let app_bar = render_scrolling(false, true).await;
+    assert_eq!(computed(&app_bar, "box-shadow"), "none");
+
+    scroll_window_to(10.).await;
+    assert_ne!(computed(&app_bar, "box-shadow"), "none");
+
+    scroll_window_to(0.).await;
+    assert_eq!(computed(&app_bar, "box-shadow"), "none");
+}
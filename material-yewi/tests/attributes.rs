@@ -0,0 +1,119 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use material_yewi::app_bar::{AppBar, AppBarProperties};
+use material_yewi::attributes::ExtraAttributes;
+use material_yewi::button::{Button, ButtonProperties};
+use wasm_bindgen_test::*;
+use web_sys::{Element, MouseEvent, MouseEventInit};
+use yew::{html, BaseComponent, Callback};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn render<C: BaseComponent>(props: C::Properties) -> Element {
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    yew::Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    root.first_element_child()
+        .expect("rendered the root element")
+}
+
+fn mouse_event(kind: &str) -> MouseEvent {
+    let mut init = MouseEventInit::new();
+    init.bubbles(true).cancelable(true);
+    MouseEvent::new_with_mouse_event_init_dict(kind, &init).unwrap()
+}
+
+/// Count the events a callback is called with
+fn counter() -> (Rc<Cell<u32>>, Callback<MouseEvent>) {
+    let count = Rc::new(Cell::new(0));
+    let count_capture = count.clone();
+    let callback = Callback::from(move |_| count_capture.set(count_capture.get() + 1));
+    (count, callback)
+}
+
+#[wasm_bindgen_test]
+async fn button_forwards_to_its_root() {
+    let (count, callback) = counter();
+    let attributes = ExtraAttributes::new()
+        .attr("id", "extra-button")
+        .attr("data-kind", "extra")
+        .attr("class", "extra-class")
+        .on_double_click(callback);
+    let button = render::<Button>(yew::props!(ButtonProperties {
+        attributes: attributes,
+        children: html! { "Press me" },
+    }))
+    .await;
+
+    assert_eq!(button.tag_name(), "BUTTON");
+    assert_eq!(button.id(), "extra-button");
+    assert_eq!(button.get_attribute("data-kind").as_deref(), Some("extra"));
+    // the class is added to the classes of the button
+    assert!(button.class_list().contains("extra-class"));
+    assert!(button.class_list().length() > 1);
+
+    button.dispatch_event(&mouse_event("dblclick")).unwrap();
+    assert_eq!(count.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn app_bar_forwards_to_its_root() {
+    let (count, callback) = counter();
+    let attributes = ExtraAttributes::new()
+        .attr("id", "extra-app-bar")
+        .attr("aria-label", "Main")
+        .on_click(callback);
+    let app_bar = render::<AppBar>(yew::props!(AppBarProperties {
+        attributes: attributes,
+        children: html! { "Title" },
+    }))
+    .await;
+
+    assert_eq!(app_bar.tag_name(), "DIV");
+    assert_eq!(app_bar.id(), "extra-app-bar");
+    assert_eq!(app_bar.get_attribute("aria-label").as_deref(), Some("Main"));
+
+    app_bar.dispatch_event(&mouse_event("click")).unwrap();
+    assert_eq!(count.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn equal_for_the_same_callbacks() {
+    let (_, callback) = counter();
+    let (_, other) = counter();
+    let extra = |callback: &Callback<MouseEvent>| {
+        ExtraAttributes::new()
+            .attr("id", "extra")
+            .on_click(callback.clone())
+    };
+    assert_eq!(extra(&callback), extra(&callback));
+    assert_ne!(extra(&callback), extra(&other));
+    assert_ne!(extra(&callback), extra(&callback).attr("id", "different"));
+}
+
+#[wasm_bindgen_test]
+async fn generic_listener_is_forwarded() {
+    let (count, callback) = counter();
+    let attributes = ExtraAttributes::new().listener(yew::html::onauxclick::Wrapper::new(callback));
+    // clones share the listener and compare equal
+    assert_eq!(attributes.clone(), attributes);
+    let (_, other) = counter();
+    assert_ne!(
+        attributes,
+        ExtraAttributes::new().listener(yew::html::onauxclick::Wrapper::new(other))
+    );
+
+    let button = render::<Button>(yew::props!(ButtonProperties {
+        attributes: attributes,
+        children: html! { "Press me" },
+    }))
+    .await;
+    button.dispatch_event(&mouse_event("auxclick")).unwrap();
+    assert_eq!(count.get(), 1);
+}
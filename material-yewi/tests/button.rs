@@ -0,0 +1,229 @@
+#![cfg(target_arch = "wasm32")]
+
+use material_styles_yew::{Motion, Theme, ThemeContents, ThemeProvider};
+use material_yewi::button::{Button, ButtonColor, ButtonProperties, ButtonSize, ButtonVariant};
+use material_yewi::fab::{Fab, FabProperties};
+use material_yewi::loading_button::{LoadingButton, LoadingButtonProperties, LoadingPosition};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::{function_component, html, Html, Properties};
+
+mod common;
+
+use common::{computed, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn render_button(props: ButtonProperties) -> Element {
+    let button = render::<Button>(props).await;
+    let root = button.parent_element().unwrap();
+    root.set_attribute("style", "width: 400px;").unwrap();
+    button
+}
+
+fn contained(color: ButtonColor) -> ButtonProperties {
+    yew::props!(ButtonProperties {
+        variant: ButtonVariant::Contained,
+        color: color,
+    })
+}
+
+#[wasm_bindgen_test]
+async fn contained_palette_colors() {
+    let error = render_button(contained(ButtonColor::Error)).await;
+    // #f44336
+    assert_eq!(computed(&error, "background-color"), "rgb(244, 67, 54)");
+    let primary = render_button(contained(ButtonColor::Primary)).await;
+    // #3f51b5
+    assert_eq!(computed(&primary, "background-color"), "rgb(63, 81, 181)");
+}
+
+#[wasm_bindgen_test]
+async fn default_and_inherit_colors_differ() {
+    let colored = |color: ButtonColor, variant: ButtonVariant| async move {
+        let button = render_button(yew::props!(ButtonProperties {
+            variant: variant,
+            color: color,
+        }))
+        .await;
+        let parent = button.parent_element().unwrap();
+        parent
+            .set_attribute("style", "color: rgb(255, 0, 0);")
+            .unwrap();
+        button
+    };
+    let default = colored(ButtonColor::Default, ButtonVariant::Text).await;
+    assert_eq!(computed(&default, "color"), "rgba(0, 0, 0, 0.87)");
+    let inherit = colored(ButtonColor::Inherit, ButtonVariant::Text).await;
+    assert_eq!(computed(&inherit, "color"), "rgb(255, 0, 0)");
+
+    let default = colored(ButtonColor::Default, ButtonVariant::Outlined).await;
+    assert_eq!(
+        computed(&default, "border-top-color"),
+        "rgba(0, 0, 0, 0.23)"
+    );
+    let inherit = colored(ButtonColor::Inherit, ButtonVariant::Outlined).await;
+    assert_eq!(computed(&inherit, "border-top-color"), "rgb(255, 0, 0)");
+
+    // Both are gray when contained
+    for color in [ButtonColor::Default, ButtonColor::Inherit].iter() {
+        let contained = colored(*color, ButtonVariant::Contained).await;
+        // #e0e0e0
+        assert_eq!(
+            computed(&contained, "background-color"),
+            "rgb(224, 224, 224)"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+async fn disable_elevation_removes_shadow() {
+    let elevated = render_button(contained(ButtonColor::Primary)).await;
+    assert_ne!(computed(&elevated, "box-shadow"), "none");
+    let mut props = contained(ButtonColor::Primary);
+    props.disable_elevation = true;
+    let flat = render_button(props).await;
+    assert_eq!(computed(&flat, "box-shadow"), "none");
+}
+
+#[wasm_bindgen_test]
+async fn full_width_fills_container() {
+    let mut props = contained(ButtonColor::Primary);
+    props.full_width = true;
+    let button = render_button(props).await;
+    assert_eq!(computed(&button, "width"), "400px");
+}
+
+/// The spans wrapping the start and end icon of the button
+fn icon_slots(button: &Element) -> (Element, Element) {
+    let slot = |selector: &str| {
+        let icon = button
+            .query_selector(selector)
+            .unwrap()
+            .expect("rendered the icon");
+        icon.parent_element().expect("the icon is wrapped")
+    };
+    (slot(".start"), slot(".end"))
+}
+
+#[wasm_bindgen_test]
+async fn icon_slots_have_size_dependent_margins() {
+    let cases = [
+        (ButtonSize::Small, "-2px", "18px"),
+        (ButtonSize::Medium, "-4px", "20px"),
+        (ButtonSize::Large, "-4px", "22px"),
+    ];
+    for (size, edge_margin, font_size) in cases.iter() {
+        let props = yew::props!(ButtonProperties {
+            size: *size,
+            start_icon: Some(html! { <i class="start">{"<"}</i> }),
+            end_icon: Some(html! { <i class="end">{">"}</i> }),
+            children: html! { "Label" },
+        });
+        let button = render_button(props).await;
+        let (start, end) = icon_slots(&button);
+        assert_eq!(start.tag_name(), "SPAN");
+        assert_eq!(computed(&start, "margin-left"), *edge_margin);
+        assert_eq!(computed(&start, "margin-right"), "8px");
+        assert_eq!(computed(&end, "margin-left"), "8px");
+        assert_eq!(computed(&end, "margin-right"), *edge_margin);
+        let start_icon = start.first_element_child().unwrap();
+        let end_icon = end.first_element_child().unwrap();
+        assert_eq!(computed(&start_icon, "font-size"), *font_size);
+        assert_eq!(computed(&end_icon, "font-size"), *font_size);
+    }
+}
+
+async fn render_loading_button(loading: bool) -> Element {
+    render::<LoadingButton>(yew::props!(LoadingButtonProperties {
+        loading: loading,
+        children: html! { "Submit a request" },
+    }))
+    .await
+}
+
+#[wasm_bindgen_test]
+async fn loading_button_is_disabled_and_keeps_its_width() {
+    let idle = render_loading_button(false).await;
+    let loading = render_loading_button(true).await;
+    assert!(!idle.has_attribute("disabled"));
+    assert!(loading.has_attribute("disabled"));
+    assert_eq!(loading.get_attribute("aria-busy").as_deref(), Some("true"));
+    assert_eq!(computed(&idle, "width"), computed(&loading, "width"));
+}
+
+#[derive(PartialEq, Properties)]
+struct LoadingHarnessProps {
+    motion: Motion,
+}
+
+#[function_component]
+fn LoadingHarness(props: &LoadingHarnessProps) -> Html {
+    let theme = Theme::from(ThemeContents {
+        motion: props.motion,
+        ..Default::default()
+    });
+    let button = yew::props!(ButtonProperties {
+        variant: ButtonVariant::Contained,
+        color: ButtonColor::Error,
+        start_icon: Some(html! { <i class="start">{"<"}</i> }),
+    });
+    html! {
+        <ThemeProvider context={theme}>
+            <LoadingButton loading={true} loading_position={LoadingPosition::Start} {button}>
+                {"Save"}
+            </LoadingButton>
+        </ThemeProvider>
+    }
+}
+
+async fn render_loading_harness(motion: Motion) -> Element {
+    render::<LoadingHarness>(LoadingHarnessProps { motion }).await
+}
+
+#[wasm_bindgen_test]
+async fn loading_button_forwards_the_button_properties() {
+    let button = render_loading_harness(Motion::Full).await;
+    assert!(button.has_attribute("disabled"));
+    assert!(button.text_content().unwrap().contains("Save"));
+    // The start icon keeps its space, but is hidden behind the indicator
+    let icon = button
+        .query_selector(".start")
+        .unwrap()
+        .expect("kept the icon");
+    assert_eq!(computed(&icon, "visibility"), "hidden");
+    // Contained buttons are disabled with the disabled background of the palette
+    assert_ne!(computed(&button, "background-color"), "rgb(244, 67, 54)");
+}
+
+#[wasm_bindgen_test]
+async fn loading_spinner_follows_the_motion_of_the_theme() {
+    let spinner = |button: &Element| {
+        let slot = button.query_selector(".start").unwrap().unwrap();
+        let indicator = slot
+            .parent_element()
+            .unwrap()
+            .next_element_sibling()
+            .unwrap();
+        indicator.first_element_child().expect("rendered a spinner")
+    };
+    let full = render_loading_harness(Motion::Full).await;
+    assert_ne!(computed(&spinner(&full), "animation-name"), "none");
+    let reduced = render_loading_harness(Motion::Reduced).await;
+    assert_eq!(computed(&spinner(&reduced), "animation-name"), "none");
+    let none = render_loading_harness(Motion::None).await;
+    assert_eq!(computed(&spinner(&none), "animation-name"), "none");
+}
+
+#[wasm_bindgen_test]
+async fn fab_floats_above_content() {
+    let fab = render::<Fab>(yew::props!(FabProperties {
+        color: ButtonColor::Primary,
+    }))
+    .await;
+    assert_eq!(computed(&fab, "z-index"), "1050");
+    assert_eq!(computed(&fab, "width"), "56px");
+    assert_ne!(computed(&fab, "box-shadow"), "none");
+    // #3f51b5
+    assert_eq!(computed(&fab, "background-color"), "rgb(63, 81, 181)");
+}
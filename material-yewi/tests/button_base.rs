@@ -0,0 +1,402 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use material_yewi::button_base::{
+    ButtonBase, ButtonBaseActions, ButtonBaseProperties, ButtonPressedEvent, ButtonType,
+    ImperativeRef, CLASS_FOCUS_VISIBLE,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{
+    HtmlButtonElement, HtmlElement, HtmlFormElement, HtmlInputElement, KeyboardEvent,
+    KeyboardEventInit, MouseEvent, MouseEventInit,
+};
+use yew::virtual_dom::AttrValue;
+use yew::{function_component, html, Callback, Html, Properties};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(PartialEq, Properties)]
+struct HarnessProps {
+    on_pressed: Callback<ButtonPressedEvent>,
+    href: Option<AttrValue>,
+    element: Option<AttrValue>,
+    disabled: bool,
+}
+
+#[function_component]
+fn Harness(props: &HarnessProps) -> Html {
+    html! {
+        <ButtonBase
+            on_pressed={props.on_pressed.clone()}
+            href={props.href.clone()}
+            element={props.element.clone()}
+            disabled={props.disabled}
+        >
+            {"Press me"}
+        </ButtonBase>
+    }
+}
+
+type PressLog = Rc<RefCell<Vec<&'static str>>>;
+
+async fn render_harness() -> (HtmlElement, PressLog) {
+    render_host(None, None).await
+}
+
+async fn render_host(
+    href: Option<&'static str>,
+    element: Option<&'static str>,
+) -> (HtmlElement, PressLog) {
+    render_props(href, element, false).await
+}
+
+async fn render_props(
+    href: Option<&'static str>,
+    element: Option<&'static str>,
+    disabled: bool,
+) -> (HtmlElement, PressLog) {
+    let log = PressLog::default();
+    let log_capture = log.clone();
+    let on_pressed = Callback::from(move |ev| {
+        log_capture.borrow_mut().push(match ev {
+            ButtonPressedEvent::MousePress(_) => "mouse",
+            ButtonPressedEvent::EnterPress(_) => "enter",
+            ButtonPressedEvent::SpacebarPress(_) => "space",
+        })
+    });
+
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let props = HarnessProps {
+        on_pressed,
+        href: href.map(AttrValue::from),
+        element: element.map(AttrValue::from),
+        disabled,
+    };
+    yew::Renderer::<Harness>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+
+    let button = root
+        .first_element_child()
+        .expect("rendered a button")
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    (button, log)
+}
+
+fn key_event(kind: &str, key: &str) -> KeyboardEvent {
+    let mut init = KeyboardEventInit::new();
+    init.key(key).bubbles(true).cancelable(true);
+    KeyboardEvent::new_with_keyboard_event_init_dict(kind, &init).unwrap()
+}
+
+fn click_event(detail: i32) -> MouseEvent {
+    let mut init = MouseEventInit::new();
+    init.detail(detail).bubbles(true).cancelable(true);
+    MouseEvent::new_with_mouse_event_init_dict("click", &init).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn mouse_click_is_mouse_press() {
+    let (button, log) = render_harness().await;
+    button.dispatch_event(&click_event(1)).unwrap();
+    assert_eq!(*log.borrow(), vec!["mouse"]);
+}
+
+#[wasm_bindgen_test]
+async fn enter_presses_on_keydown() {
+    let (button, log) = render_harness().await;
+    button
+        .dispatch_event(&key_event("keydown", "Enter"))
+        .unwrap();
+    // the browser synthesizes a click without click count for native buttons
+    button.dispatch_event(&click_event(0)).unwrap();
+    button.dispatch_event(&key_event("keyup", "Enter")).unwrap();
+    assert_eq!(*log.borrow(), vec!["enter"]);
+}
+
+#[wasm_bindgen_test]
+async fn space_presses_on_keyup() {
+    let (button, log) = render_harness().await;
+    button.dispatch_event(&key_event("keydown", " ")).unwrap();
+    assert!(log.borrow().is_empty());
+    button.dispatch_event(&key_event("keyup", " ")).unwrap();
+    button.dispatch_event(&click_event(0)).unwrap();
+    assert_eq!(*log.borrow(), vec!["space"]);
+}
+
+#[wasm_bindgen_test]
+async fn ripple_shows_while_space_is_held() {
+    let (button, _) = render_harness().await;
+    let count = |selector: &str| button.query_selector_all(selector).unwrap().length();
+
+    button.focus().unwrap();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(".ripplePulsate > .child:not(.childLeaving)"), 1);
+
+    button.dispatch_event(&key_event("keydown", " ")).unwrap();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(".ripplePulsate > .child:not(.childLeaving)"), 0);
+    assert_eq!(
+        count(".ripple:not(.ripplePulsate) > .child:not(.childLeaving)"),
+        1
+    );
+
+    button.dispatch_event(&key_event("keyup", " ")).unwrap();
+    TimeoutFuture::new(0).await;
+    assert_eq!(
+        count(".ripple:not(.ripplePulsate) > .child:not(.childLeaving)"),
+        0
+    );
+    assert_eq!(count(".ripplePulsate > .child:not(.childLeaving)"), 1);
+}
+
+#[wasm_bindgen_test]
+async fn renders_link_with_href() {
+    let (link, log) = render_host(Some("#target"), None).await;
+    assert_eq!(link.tag_name(), "A");
+    assert_eq!(link.get_attribute("href").as_deref(), Some("#target"));
+    assert_eq!(link.get_attribute("role"), None);
+
+    // links are only activated by Enter
+    link.dispatch_event(&key_event("keyup", " ")).unwrap();
+    link.dispatch_event(&key_event("keydown", "Enter")).unwrap();
+    link.dispatch_event(&click_event(0)).unwrap();
+    assert_eq!(*log.borrow(), vec!["enter"]);
+}
+
+#[wasm_bindgen_test]
+async fn disabled_link_does_not_navigate() {
+    let (link, log) = render_props(Some("#disabled-target"), None, true).await;
+    assert_eq!(link.get_attribute("aria-disabled").as_deref(), Some("true"));
+    assert_eq!(link.get_attribute("tabindex").as_deref(), Some("-1"));
+
+    link.focus().unwrap();
+    let enter = key_event("keydown", "Enter");
+    link.dispatch_event(&enter).unwrap();
+    assert!(enter.default_prevented());
+
+    let click = click_event(0);
+    link.dispatch_event(&click).unwrap();
+    assert!(click.default_prevented());
+    link.click();
+    TimeoutFuture::new(0).await;
+    assert_ne!(
+        gloo::utils::window().location().hash().unwrap(),
+        "#disabled-target"
+    );
+    assert!(log.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn non_native_host_is_activated_by_keyboard() {
+    let (host, log) = render_host(None, Some("div")).await;
+    assert_eq!(host.tag_name(), "DIV");
+    assert_eq!(host.get_attribute("role").as_deref(), Some("button"));
+    assert_eq!(host.get_attribute("tabindex").as_deref(), Some("0"));
+
+    let enter = key_event("keydown", "Enter");
+    host.dispatch_event(&enter).unwrap();
+    assert!(enter.default_prevented());
+    assert_eq!(*log.borrow(), vec!["enter"]);
+
+    let space = key_event("keydown", " ");
+    host.dispatch_event(&space).unwrap();
+    assert!(space.default_prevented());
+    assert_eq!(*log.borrow(), vec!["enter"]);
+    host.dispatch_event(&key_event("keyup", " ")).unwrap();
+    assert_eq!(*log.borrow(), vec!["enter", "space"]);
+}
+
+#[wasm_bindgen_test]
+async fn non_link_host_with_href_keeps_the_button_role() {
+    let (host, _) = render_host(Some("#target"), Some("div")).await;
+    assert_eq!(host.tag_name(), "DIV");
+    assert_eq!(host.get_attribute("role").as_deref(), Some("button"));
+}
+
+#[wasm_bindgen_test]
+async fn native_button_does_not_submit_by_default() {
+    let (button, _) = render_harness().await;
+    assert_eq!(button.get_attribute("type").as_deref(), Some("button"));
+
+    let (host, _) = render_host(None, Some("div")).await;
+    assert_eq!(host.get_attribute("type"), None);
+}
+
+/// Render a button into a new container, appended to `parent`
+async fn render_button_in(
+    parent: &web_sys::Element,
+    props: ButtonBaseProperties,
+) -> HtmlButtonElement {
+    let root = document().create_element("div").unwrap();
+    parent.append_child(&root).unwrap();
+    yew::Renderer::<ButtonBase>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    root.first_element_child()
+        .expect("rendered a button")
+        .dyn_into::<HtmlButtonElement>()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn submits_name_and_value_to_the_given_form() {
+    let form = document()
+        .create_element("form")
+        .unwrap()
+        .dyn_into::<HtmlFormElement>()
+        .unwrap();
+    form.set_id("button-base-external-form");
+    document().body().unwrap().append_child(&form).unwrap();
+
+    let submitted = Rc::new(RefCell::new(None));
+    let submitted_capture = submitted.clone();
+    let _listener = EventListener::new(&form, "submit", move |ev| {
+        ev.prevent_default();
+        let submitter = js_sys::Reflect::get(ev, &"submitter".into())
+            .unwrap()
+            .dyn_into::<HtmlButtonElement>()
+            .unwrap();
+        *submitted_capture.borrow_mut() = Some((submitter.name(), submitter.value()));
+    });
+
+    // The button is rendered outside of the form, and refers to it by id
+    let props = yew::props!(ButtonBaseProperties {
+        button_type: ButtonType::Submit,
+        name: "action",
+        value: "save",
+        form: "button-base-external-form",
+    });
+    let button = render_button_in(&document().body().unwrap(), props).await;
+    assert_eq!(button.type_(), "submit");
+    assert_eq!(button.get_attribute("value").as_deref(), Some("save"));
+    assert_eq!(button.form(), Some(form));
+
+    button.click();
+    assert_eq!(
+        submitted.borrow_mut().take(),
+        Some(("action".to_string(), "save".to_string()))
+    );
+}
+
+#[wasm_bindgen_test]
+async fn value_is_only_set_on_native_buttons() {
+    let render = |props: ButtonBaseProperties| async move {
+        let root = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&root).unwrap();
+        yew::Renderer::<ButtonBase>::with_root_and_props(root.clone(), props).render();
+        TimeoutFuture::new(0).await;
+        root.first_element_child().expect("rendered a host")
+    };
+    let link = render(yew::props!(ButtonBaseProperties {
+        href: "#target",
+        value: "save",
+    }))
+    .await;
+    assert_eq!(link.get_attribute("value"), None);
+    assert_eq!(
+        js_sys::Reflect::get(&link, &"value".into()).unwrap(),
+        JsValue::UNDEFINED
+    );
+
+    let host = render(yew::props!(ButtonBaseProperties {
+        element: "div",
+        name: "action",
+        value: "save",
+        form: "button-base-external-form",
+    }))
+    .await;
+    assert_eq!(host.get_attribute("value"), None);
+    assert_eq!(
+        js_sys::Reflect::get(&host, &"value".into()).unwrap(),
+        JsValue::UNDEFINED
+    );
+    assert_eq!(host.get_attribute("name"), None);
+    assert_eq!(host.get_attribute("form"), None);
+
+    // A native button without a value renders none either
+    let (button, _) = render_harness().await;
+    assert_eq!(button.get_attribute("value"), None);
+}
+
+#[wasm_bindgen_test]
+async fn reset_button_resets_its_form() {
+    let form = document().create_element("form").unwrap();
+    document().body().unwrap().append_child(&form).unwrap();
+    let input = document()
+        .create_element("input")
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+    input.set_default_value("initial");
+    form.append_child(&input).unwrap();
+
+    let submitted = Rc::new(RefCell::new(false));
+    let submitted_capture = submitted.clone();
+    let _listener = EventListener::new(&form, "submit", move |ev| {
+        ev.prevent_default();
+        *submitted_capture.borrow_mut() = true;
+    });
+
+    let props = yew::props!(ButtonBaseProperties {
+        button_type: ButtonType::Reset,
+    });
+    let button = render_button_in(&form, props).await;
+    assert_eq!(button.type_(), "reset");
+
+    input.set_value("changed");
+    button.click();
+    assert_eq!(input.value(), "initial");
+    assert!(!*submitted.borrow());
+}
+
+#[derive(PartialEq, Properties)]
+struct ActionHarnessProps {
+    action: ImperativeRef<ButtonBaseActions>,
+}
+
+#[function_component]
+fn ActionHarness(props: &ActionHarnessProps) -> Html {
+    html! {
+        <ButtonBase action={props.action.clone()}>
+            {"Default action"}
+        </ButtonBase>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn actions_focus_the_button() {
+    let action = ImperativeRef::<ButtonBaseActions>::new();
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let props = ActionHarnessProps {
+        action: action.clone(),
+    };
+    yew::Renderer::<ActionHarness>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    let button = root.first_element_child().unwrap();
+    let actions = || action.get().as_deref().cloned().expect("actions are bound");
+
+    actions().focus_visible();
+    TimeoutFuture::new(0).await;
+    assert_eq!(document().active_element(), Some(button.clone()));
+    assert!(button.class_list().contains(CLASS_FOCUS_VISIBLE));
+    assert_eq!(
+        button
+            .query_selector_all(".ripplePulsate > .child:not(.childLeaving)")
+            .unwrap()
+            .length(),
+        1
+    );
+
+    actions().blur();
+    TimeoutFuture::new(0).await;
+    assert_ne!(document().active_element(), Some(button.clone()));
+    assert!(!button.class_list().contains(CLASS_FOCUS_VISIBLE));
+}
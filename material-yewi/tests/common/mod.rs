@@ -0,0 +1,58 @@
+//! Helpers shared by the browser tests, included with `mod common;`. Not every test uses every
+//! helper.
+#![allow(dead_code)]
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::{document, window};
+use web_sys::Element;
+use yew::BaseComponent;
+
+/// Render a component into a new container appended to the body, returning the container.
+pub async fn mount<C: BaseComponent>(props: C::Properties) -> Element {
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    yew::Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    root
+}
+
+/// Render a component into a new container, returning the element it rendered.
+pub async fn render<C: BaseComponent>(props: C::Properties) -> Element {
+    let root = mount::<C>(props).await;
+    root.first_element_child()
+        .expect("rendered the root element")
+}
+
+/// The computed value of a css property of an element.
+pub fn computed(element: &Element, property: &str) -> String {
+    let style = window().get_computed_style(element).unwrap().unwrap();
+    style.get_property_value(property).unwrap()
+}
+
+/// Makes the document taller than the window, so that it can be scrolled, while alive.
+///
+/// Dropping it scrolls back to the top and removes the spacer, so that later tests start from an
+/// unscrolled document of the usual height.
+pub struct ScrollSpacer(Element);
+
+impl Drop for ScrollSpacer {
+    fn drop(&mut self) {
+        window().scroll_to_with_x_and_y(0., 0.);
+        self.0.remove();
+    }
+}
+
+/// Make the document scrollable until the end of the test, keep the returned spacer alive.
+#[must_use = "the document is only scrollable while the spacer is alive"]
+pub fn make_scrollable() -> ScrollSpacer {
+    let spacer = document().create_element("div").unwrap();
+    spacer.set_attribute("style", "height: 5000px;").unwrap();
+    document().body().unwrap().append_child(&spacer).unwrap();
+    ScrollSpacer(spacer)
+}
+
+pub async fn scroll_window_to(y: f64) {
+    window().scroll_to_with_x_and_y(0., y);
+    // scroll events are dispatched with the next frame, and transitions take up to 300ms
+    TimeoutFuture::new(400).await;
+}
@@ -0,0 +1,202 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::utils::document;
+use js_sys::{Array, Reflect};
+use material_styles_yew::Theme;
+use material_yewi::attributes::ExtraAttributes;
+use material_yewi::button::{ButtonColor, ButtonSize};
+use material_yewi::fab::{Fab, FabProperties, FabVariant};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{CssStyleDeclaration, Element};
+use yew::html;
+
+mod common;
+
+use common::{computed, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn render_fab(props: FabProperties) -> Element {
+    render::<Fab>(props).await
+}
+
+/// The computed value of a css property, to compare against values in another notation
+fn normalized(property: &str, value: &str) -> String {
+    let probe = document().create_element("div").unwrap();
+    probe
+        .set_attribute("style", &format!("{}: {};", property, value))
+        .unwrap();
+    document().body().unwrap().append_child(&probe).unwrap();
+    let normalized = computed(&probe, property);
+    probe.remove();
+    normalized
+}
+
+fn get(target: &JsValue, key: &str) -> JsValue {
+    Reflect::get(target, &JsValue::from_str(key)).unwrap()
+}
+
+/// The value of a property in the `:active` rule matching the element. Styles of pseudo classes
+/// can not be computed without actually pressing the element.
+fn active_style(element: &Element, property: &str) -> Option<String> {
+    let sheets = Array::from(&get(&document(), "styleSheets"));
+    sheets
+        .iter()
+        .flat_map(|sheet| {
+            Array::from(&get(&sheet, "cssRules"))
+                .iter()
+                .collect::<Vec<_>>()
+        })
+        .filter_map(|rule| {
+            let selector = get(&rule, "selectorText").as_string()?;
+            let base = selector.strip_suffix(":active")?;
+            element.matches(base).ok()?.then(|| rule)
+        })
+        .filter_map(|rule| {
+            let style: CssStyleDeclaration = get(&rule, "style").unchecked_into();
+            let value = style.get_property_value(property).ok()?;
+            (!value.is_empty()).then(|| value)
+        })
+        .last()
+}
+
+#[wasm_bindgen_test]
+async fn circular_sizes() {
+    let cases = [
+        (ButtonSize::Small, "40px"),
+        (ButtonSize::Medium, "48px"),
+        (ButtonSize::Large, "56px"),
+    ];
+    for (size, extent) in cases.iter() {
+        let fab = render_fab(yew::props!(FabProperties {
+            aria_label: Some("add".into()),
+            size: *size,
+        }))
+        .await;
+        assert_eq!(computed(&fab, "width"), *extent);
+        assert_eq!(computed(&fab, "height"), *extent);
+        assert_eq!(computed(&fab, "border-radius"), "50%");
+    }
+}
+
+#[wasm_bindgen_test]
+async fn extended_is_pill_shaped() {
+    let cases = [
+        (ButtonSize::Small, "34px", "17px"),
+        (ButtonSize::Medium, "40px", "20px"),
+        (ButtonSize::Large, "48px", "24px"),
+    ];
+    for (size, height, radius) in cases.iter() {
+        let fab = render_fab(yew::props!(FabProperties {
+            variant: FabVariant::Extended,
+            size: *size,
+            children: html! { "Navigate somewhere" },
+        }))
+        .await;
+        assert_eq!(computed(&fab, "height"), *height);
+        assert_eq!(computed(&fab, "border-radius"), *radius);
+        let width: f64 = computed(&fab, "width")
+            .trim_end_matches("px")
+            .parse()
+            .unwrap();
+        assert!(width > 56., "extended buttons fit their label");
+    }
+}
+
+#[wasm_bindgen_test]
+async fn elevation_raises_while_pressed() {
+    let shadows = &Theme::default().shadows;
+    let fab = render_fab(yew::props!(FabProperties {
+        aria_label: Some("add".into()),
+        color: ButtonColor::Primary,
+    }))
+    .await;
+    assert_eq!(
+        computed(&fab, "box-shadow"),
+        normalized("box-shadow", &shadows[6].to_string())
+    );
+    let active = active_style(&fab, "box-shadow").expect("a shadow while pressed");
+    assert_eq!(
+        normalized("box-shadow", &active),
+        normalized("box-shadow", &shadows[12].to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+async fn disabled_has_no_shadow() {
+    let actions = &Theme::default().palette.actions;
+    let fab = render_fab(yew::props!(FabProperties {
+        aria_label: Some("add".into()),
+        color: ButtonColor::Primary,
+        disabled: true,
+    }))
+    .await;
+    assert!(fab.has_attribute("disabled"));
+    assert_eq!(computed(&fab, "box-shadow"), "none");
+    assert_eq!(
+        computed(&fab, "background-color"),
+        normalized(
+            "background-color",
+            &actions.disabled_background.to_css_value()
+        )
+    );
+    assert_eq!(
+        computed(&fab, "color"),
+        normalized("color", &actions.disabled.to_css_value())
+    );
+}
+
+#[wasm_bindgen_test]
+async fn labelled_like_icon_buttons() {
+    let labelled = render_fab(yew::props!(FabProperties {
+        aria_label: Some("add".into()),
+    }))
+    .await;
+    assert_eq!(labelled.get_attribute("aria-label").as_deref(), Some("add"));
+
+    let attributes = ExtraAttributes::new().attr("aria-labelledby", "add-label");
+    let labelled_by = render_fab(yew::props!(FabProperties {
+        attributes: attributes,
+    }))
+    .await;
+    assert_eq!(labelled_by.get_attribute("aria-label"), None);
+    assert_eq!(
+        labelled_by.get_attribute("aria-labelledby").as_deref(),
+        Some("add-label")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn forwards_the_button_base_properties() {
+    let submit = render_fab(yew::props!(FabProperties {
+        aria_label: Some("save".into()),
+        name: "action",
+        value: "save",
+        form: "fab-form",
+    }))
+    .await;
+    assert_eq!(submit.get_attribute("name").as_deref(), Some("action"));
+    assert_eq!(submit.get_attribute("value").as_deref(), Some("save"));
+    assert_eq!(submit.get_attribute("form").as_deref(), Some("fab-form"));
+
+    let download = render_fab(yew::props!(FabProperties {
+        aria_label: Some("download".into()),
+        href: "#file",
+        download: "file.txt",
+    }))
+    .await;
+    assert_eq!(download.tag_name(), "A");
+    assert_eq!(
+        download.get_attribute("download").as_deref(),
+        Some("file.txt")
+    );
+
+    let span = render_fab(yew::props!(FabProperties {
+        aria_label: Some("add".into()),
+        element: "span",
+    }))
+    .await;
+    assert_eq!(span.tag_name(), "SPAN");
+    assert_eq!(span.get_attribute("role").as_deref(), Some("button"));
+}
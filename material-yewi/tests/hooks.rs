@@ -0,0 +1,516 @@
+#![cfg(target_arch = "wasm32")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use material_styles_yew::Breakpoint;
+use material_yewi::hooks::{
+    bind_imperative_ref, use_breakpoint_up, use_controlled, use_event_callback, use_focus_visible,
+    use_fork_ref, use_id, use_media_query, use_scroll_trigger, IdProvider, ImperativeRef,
+    MediaQueryOptions, ScrollTriggerOptions, UseStateHandleExt,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, KeyboardEvent, KeyboardEventInit};
+use yew::virtual_dom::AttrValue;
+use yew::{function_component, html, use_state, Callback, Html, NodeRef, Properties};
+
+mod common;
+
+use common::{make_scrollable, mount, scroll_window_to};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn click(root: &Element, selector: &str) {
+    let element = root.query_selector(selector).unwrap().unwrap();
+    element.dyn_into::<HtmlElement>().unwrap().click();
+}
+
+#[derive(PartialEq, Properties)]
+struct ControlledProps {
+    value: Option<u32>,
+}
+
+#[function_component]
+fn Controlled(props: &ControlledProps) -> Html {
+    let (value, set_value) = use_controlled(props.value, 0);
+    let onclick = Callback::from(move |_| set_value.emit(value + 1));
+    html! { <button {onclick}>{value}</button> }
+}
+
+#[wasm_bindgen_test]
+async fn use_controlled_keeps_internal_state_unless_controlled() {
+    let uncontrolled = mount::<Controlled>(ControlledProps { value: None }).await;
+    click(&uncontrolled, "button");
+    TimeoutFuture::new(0).await;
+    assert_eq!(uncontrolled.text_content().as_deref(), Some("1"));
+
+    let controlled = mount::<Controlled>(ControlledProps { value: Some(5) }).await;
+    click(&controlled, "button");
+    TimeoutFuture::new(0).await;
+    assert_eq!(controlled.text_content().as_deref(), Some("5"));
+}
+
+#[derive(PartialEq, Properties)]
+struct EventCallbackProps {
+    log: Rc<RefCell<Vec<u32>>>,
+    callbacks: Rc<RefCell<Vec<Callback<()>>>>,
+}
+
+#[function_component]
+fn EventCallback(props: &EventCallbackProps) -> Html {
+    let count = use_state(|| 0u32);
+    let log = props.log.clone();
+    let current = *count;
+    let callback = use_event_callback(move |_: ()| log.borrow_mut().push(current));
+    props.callbacks.borrow_mut().push(callback);
+    let onclick = Callback::from(move |_| count.set(current + 1));
+    html! { <button {onclick}>{current}</button> }
+}
+
+#[wasm_bindgen_test]
+async fn use_event_callback_is_stable_and_calls_latest_closure() {
+    let props = EventCallbackProps {
+        log: Rc::default(),
+        callbacks: Rc::default(),
+    };
+    let log = props.log.clone();
+    let callbacks = props.callbacks.clone();
+    let root = mount::<EventCallback>(props).await;
+    click(&root, "button");
+    TimeoutFuture::new(0).await;
+
+    let callbacks = callbacks.borrow();
+    assert_eq!(callbacks.len(), 2);
+    assert_eq!(callbacks[0], callbacks[1]);
+    callbacks[0].emit(());
+    assert_eq!(*log.borrow(), vec![1]);
+}
+
+#[derive(PartialEq, Properties)]
+struct IdProps {
+    id: Option<AttrValue>,
+}
+
+#[function_component]
+fn Labelled(props: &IdProps) -> Html {
+    let id = use_id(props.id.clone());
+    let rerender = use_state(|| 0u32);
+    let onclick = Callback::from(move |_| rerender.set(*rerender + 1));
+    html! { <button id={id} {onclick}>{"Label"}</button> }
+}
+
+#[wasm_bindgen_test]
+async fn use_id_is_unique_and_stable() {
+    let first = mount::<Labelled>(IdProps { id: None }).await;
+    let second = mount::<Labelled>(IdProps { id: None }).await;
+    let id_of = |root: &Element| root.query_selector("button").unwrap().unwrap().id();
+    let first_id = id_of(&first);
+    assert!(!first_id.is_empty());
+    assert_ne!(first_id, id_of(&second));
+
+    click(&first, "button");
+    TimeoutFuture::new(0).await;
+    assert_eq!(id_of(&first), first_id);
+
+    let given = mount::<Labelled>(IdProps {
+        id: Some("given".into()),
+    })
+    .await;
+    assert_eq!(id_of(&given), "given");
+}
+
+#[function_component]
+fn ProvidedIds() -> Html {
+    html! {
+        <IdProvider>
+            <Labelled id={None::<AttrValue>} />
+            <IdProvider>
+                <Labelled id={None::<AttrValue>} />
+            </IdProvider>
+            <Labelled id={None::<AttrValue>} />
+        </IdProvider>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_id_is_deterministic_inside_provider() {
+    let ids = |root: &Element| {
+        let buttons = root.query_selector_all("button").unwrap();
+        (0..buttons.length())
+            .map(|i| buttons.item(i).unwrap().dyn_into::<Element>().unwrap().id())
+            .collect::<Vec<_>>()
+    };
+    let first = mount::<ProvidedIds>(()).await;
+    let second = mount::<ProvidedIds>(()).await;
+    assert_eq!(ids(&first), vec!["mwi-0", "mwi-1-0", "mwi-2"]);
+    assert_eq!(ids(&first), ids(&second));
+}
+
+#[function_component]
+fn RenamedIds() -> Html {
+    let prefix = use_state(|| AttrValue::from("first"));
+    let onclick = {
+        let prefix = prefix.clone();
+        Callback::from(move |_| prefix.set("second".into()))
+    };
+    html! {
+        <>
+            <IdProvider prefix={(*prefix).clone()}>
+                <Labelled id={None::<AttrValue>} />
+            </IdProvider>
+            <span id="rename" {onclick} />
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn id_provider_follows_its_prefix() {
+    let root = mount::<RenamedIds>(()).await;
+    let id_of = |root: &Element| root.query_selector("button").unwrap().unwrap().id();
+    assert_eq!(id_of(&root), "first-0");
+
+    click(&root, "#rename");
+    TimeoutFuture::new(0).await;
+    assert_eq!(id_of(&root), "second-0");
+}
+
+#[derive(PartialEq, Properties)]
+struct ForkRefProps {
+    own_ref: NodeRef,
+    refs: Vec<NodeRef>,
+}
+
+#[function_component]
+fn ForkedRefs(props: &ForkRefProps) -> Html {
+    let fork = use_fork_ref(&props.refs);
+    fork.bind(html! { <span id="forked" ref={props.own_ref.clone()} /> })
+}
+
+#[wasm_bindgen_test]
+async fn use_fork_ref_binds_every_ref() {
+    let own_ref = NodeRef::default();
+    let refs = vec![NodeRef::default(), NodeRef::default()];
+    let props = ForkRefProps {
+        own_ref: own_ref.clone(),
+        refs: refs.clone(),
+    };
+    let root = mount::<ForkedRefs>(props).await;
+    let span = root
+        .query_selector("#forked")
+        .unwrap()
+        .expect("rendered a span");
+    assert_eq!(own_ref.cast::<Element>(), Some(span.clone()));
+    for node_ref in refs.iter() {
+        assert_eq!(node_ref.cast::<Element>(), Some(span.clone()));
+    }
+    // the refs add no elements of their own
+    assert_eq!(root.child_element_count(), 1);
+}
+
+#[derive(PartialEq, Properties)]
+struct RelaxedSetProps {
+    renders: Rc<RefCell<u32>>,
+}
+
+#[function_component]
+fn RelaxedSet(props: &RelaxedSetProps) -> Html {
+    *props.renders.borrow_mut() += 1;
+    let state = use_state(|| 1u32);
+    let onclick = Callback::from(move |_| state.relaxed_set(1));
+    html! { <button {onclick} /> }
+}
+
+#[wasm_bindgen_test]
+async fn relaxed_set_skips_equal_values() {
+    let renders = Rc::new(RefCell::new(0));
+    let root = mount::<RelaxedSet>(RelaxedSetProps {
+        renders: renders.clone(),
+    })
+    .await;
+    click(&root, "button");
+    TimeoutFuture::new(0).await;
+    assert_eq!(*renders.borrow(), 1);
+}
+
+#[derive(PartialEq, Properties)]
+struct FocusVisibleProps {
+    visible: ImperativeRef<Rc<RefCell<bool>>>,
+}
+
+#[function_component]
+fn FocusVisible(props: &FocusVisibleProps) -> Html {
+    let node_ref = yew::use_node_ref();
+    let handle = use_focus_visible(&node_ref);
+    bind_imperative_ref(&props.visible, handle.is_visible.clone());
+    html! {
+        <button ref={node_ref} onfocus={handle.onfocus} onblur={handle.onblur} />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_focus_visible_tracks_keyboard_focus() {
+    let visible = ImperativeRef::new();
+    let root = mount::<FocusVisible>(FocusVisibleProps {
+        visible: visible.clone(),
+    })
+    .await;
+    let is_visible = || *visible.get().expect("bound").borrow();
+    assert!(!is_visible());
+
+    let button = root.first_element_child().unwrap();
+    // programmatic focus without prior pointer interaction is visible
+    button.dyn_ref::<HtmlElement>().unwrap().focus().unwrap();
+    assert!(is_visible());
+    button.dyn_ref::<HtmlElement>().unwrap().blur().unwrap();
+    assert!(!is_visible());
+}
+
+/// Records the listeners added to and removed from the document, and hides native support for
+/// `:focus-visible` if asked to, until dropped.
+struct DocumentSpy(JsValue);
+
+impl DocumentSpy {
+    fn install(hide_native: bool) -> Self {
+        let install = js_sys::Function::new_with_args(
+            "hideNative",
+            "const doc = document;
+            const root = doc.documentElement;
+            const log = [];
+            const add = doc.addEventListener;
+            const remove = doc.removeEventListener;
+            doc.addEventListener = function (type, l, o) {
+                log.push('+' + type);
+                return add.call(this, type, l, o);
+            };
+            doc.removeEventListener = function (type, l, o) {
+                log.push('-' + type);
+                return remove.call(this, type, l, o);
+            };
+            if (hideNative) {
+                const matches = root.matches;
+                root.matches = function (selector) {
+                    if (selector.includes(':focus-visible')) {
+                        throw new SyntaxError('unsupported selector');
+                    }
+                    return matches.call(this, selector);
+                };
+            }
+            return {
+                log,
+                restore() {
+                    delete doc.addEventListener;
+                    delete doc.removeEventListener;
+                    delete root.matches;
+                },
+            };",
+        );
+        Self(install.call1(&JsValue::NULL, &hide_native.into()).unwrap())
+    }
+
+    /// How often a listener for the event was added and removed
+    fn count(&self, event: &str) -> (usize, usize) {
+        let log: js_sys::Array = js_sys::Reflect::get(&self.0, &"log".into())
+            .unwrap()
+            .unchecked_into();
+        let entries: Vec<String> = log.iter().filter_map(|e| e.as_string()).collect();
+        let added = entries.iter().filter(|e| **e == format!("+{}", event));
+        let removed = entries.iter().filter(|e| **e == format!("-{}", event));
+        (added.count(), removed.count())
+    }
+}
+
+impl Drop for DocumentSpy {
+    fn drop(&mut self) {
+        let restore: js_sys::Function = js_sys::Reflect::get(&self.0, &"restore".into())
+            .unwrap()
+            .unchecked_into();
+        restore.call0(&self.0).unwrap();
+    }
+}
+
+#[function_component]
+fn FocusVisibleList() -> Html {
+    let count = use_state(|| 2usize);
+    let remove = {
+        let count = count.clone();
+        Callback::from(move |_| count.set(count.saturating_sub(1)))
+    };
+    html! {
+        <>
+            <button id="remove" onclick={remove} />
+            { for (0..*count).map(|_| html! { <FocusVisible visible={ImperativeRef::new()} /> }) }
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_focus_visible_removes_listeners_with_last_user() {
+    let spy = DocumentSpy::install(true);
+    let root = mount::<FocusVisibleList>(()).await;
+    // both users share the listeners of the document
+    assert_eq!(spy.count("keydown"), (1, 0));
+    assert_eq!(spy.count("pointerdown"), (1, 0));
+
+    click(&root, "#remove");
+    TimeoutFuture::new(0).await;
+    assert_eq!(spy.count("keydown"), (1, 0));
+
+    click(&root, "#remove");
+    TimeoutFuture::new(0).await;
+    assert_eq!(spy.count("keydown"), (1, 1));
+    assert_eq!(spy.count("pointerdown"), (1, 1));
+    assert_eq!(spy.count("visibilitychange"), (1, 1));
+}
+
+#[wasm_bindgen_test]
+async fn use_focus_visible_polyfill_tracks_the_keyboard() {
+    let spy = DocumentSpy::install(true);
+    let visible = ImperativeRef::new();
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let props = FocusVisibleProps {
+        visible: visible.clone(),
+    };
+    let app = yew::Renderer::<FocusVisible>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    assert_eq!(spy.count("keydown"), (1, 0));
+    let is_visible = || *visible.get().expect("bound").borrow();
+    let button = root
+        .first_element_child()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+
+    // a pointer interaction hides the focus
+    let pointer = web_sys::Event::new("mousedown").unwrap();
+    document().dispatch_event(&pointer).unwrap();
+    button.focus().unwrap();
+    assert!(!is_visible());
+    button.blur().unwrap();
+
+    let mut init = KeyboardEventInit::new();
+    init.key("Tab");
+    let tab = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    document().dispatch_event(&tab).unwrap();
+    button.focus().unwrap();
+    assert!(is_visible());
+    button.blur().unwrap();
+
+    app.destroy();
+    TimeoutFuture::new(0).await;
+    assert_eq!(spy.count("keydown"), (1, 1));
+}
+
+#[wasm_bindgen_test]
+async fn use_focus_visible_prefers_native_support() {
+    let native = document()
+        .document_element()
+        .map_or(false, |e| e.matches(":focus-visible").is_ok());
+    if !native {
+        return;
+    }
+    let spy = DocumentSpy::install(false);
+    let visible = ImperativeRef::new();
+    let root = mount::<FocusVisible>(FocusVisibleProps {
+        visible: visible.clone(),
+    })
+    .await;
+    // the browser tracks the keyboard itself
+    assert_eq!(spy.count("keydown"), (0, 0));
+
+    let button = root
+        .first_element_child()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    button.focus().unwrap();
+    let is_visible = *visible.get().expect("bound").borrow();
+    assert_eq!(is_visible, button.matches(":focus-visible").unwrap());
+    button.blur().unwrap();
+}
+
+#[derive(PartialEq, Properties)]
+struct MediaQueryProps {
+    query: &'static str,
+    options: MediaQueryOptions,
+}
+
+#[function_component]
+fn MediaQuery(props: &MediaQueryProps) -> Html {
+    let matches = use_media_query(props.query, props.options);
+    let xs_up = use_breakpoint_up(Breakpoint::Xs, props.options);
+    html! { <span>{format!("{} {}", matches, xs_up)}</span> }
+}
+
+#[wasm_bindgen_test]
+async fn use_media_query_evaluates_after_first_render() {
+    let options = MediaQueryOptions {
+        default_matches: true,
+        no_ssr: false,
+    };
+    let root = mount::<MediaQuery>(MediaQueryProps {
+        query: "(max-width: 0px)",
+        options,
+    })
+    .await;
+    assert_eq!(root.text_content().as_deref(), Some("false true"));
+
+    let no_ssr = mount::<MediaQuery>(MediaQueryProps {
+        query: "(min-width: 0px)",
+        options: MediaQueryOptions {
+            default_matches: false,
+            no_ssr: true,
+        },
+    })
+    .await;
+    assert_eq!(no_ssr.text_content().as_deref(), Some("true true"));
+}
+
+#[derive(PartialEq, Properties)]
+struct ScrollTriggerProps {
+    disable_hysteresis: bool,
+}
+
+#[function_component]
+fn ScrollTrigger(props: &ScrollTriggerProps) -> Html {
+    let triggered = use_scroll_trigger(ScrollTriggerOptions {
+        threshold: 100.,
+        disable_hysteresis: props.disable_hysteresis,
+        ..Default::default()
+    });
+    html! { <span>{ triggered.to_string() }</span> }
+}
+
+#[wasm_bindgen_test]
+async fn use_scroll_trigger_follows_the_window() {
+    let _spacer = make_scrollable();
+    let with_hysteresis = mount::<ScrollTrigger>(ScrollTriggerProps {
+        disable_hysteresis: false,
+    })
+    .await;
+    let without_hysteresis = mount::<ScrollTrigger>(ScrollTriggerProps {
+        disable_hysteresis: true,
+    })
+    .await;
+    let triggered = || {
+        (
+            with_hysteresis.text_content().unwrap(),
+            without_hysteresis.text_content().unwrap(),
+        )
+    };
+    let both = |value: &str| (value.to_string(), value.to_string());
+    assert_eq!(triggered(), both("false"));
+
+    scroll_window_to(50.).await;
+    assert_eq!(triggered(), both("false"));
+    scroll_window_to(200.).await;
+    assert_eq!(triggered(), both("true"));
+    // scrolling back up releases the trigger, unless the hysteresis is disabled
+    scroll_window_to(150.).await;
+    assert_eq!(triggered(), ("false".to_string(), "true".to_string()));
+    scroll_window_to(0.).await;
+    assert_eq!(triggered(), both("false"));
+}
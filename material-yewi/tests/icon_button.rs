@@ -0,0 +1,183 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use material_yewi::attributes::ExtraAttributes;
+use material_yewi::button::{ButtonColor, ButtonSize};
+use material_yewi::icon_button::{IconButton, IconButtonEdge, IconButtonProperties};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, MouseEvent, MouseEventInit};
+use yew::html;
+
+mod common;
+
+use common::{computed, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn render_icon_button(props: IconButtonProperties) -> Element {
+    render::<IconButton>(props).await
+}
+
+#[wasm_bindgen_test]
+async fn labelled_circular_button() {
+    let button = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("delete".into()),
+    }))
+    .await;
+    assert_eq!(
+        button.get_attribute("aria-label").as_deref(),
+        Some("delete")
+    );
+    assert_eq!(computed(&button, "border-radius"), "50%");
+}
+
+#[wasm_bindgen_test]
+async fn palette_color() {
+    let button = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("delete".into()),
+        color: ButtonColor::Error,
+    }))
+    .await;
+    // #f44336
+    assert_eq!(computed(&button, "color"), "rgb(244, 67, 54)");
+}
+
+#[wasm_bindgen_test]
+async fn labelled_by_another_element() {
+    let attributes = ExtraAttributes::new().attr("aria-labelledby", "delete-label");
+    let button = render_icon_button(yew::props!(IconButtonProperties {
+        attributes: attributes,
+    }))
+    .await;
+    assert_eq!(button.get_attribute("aria-label"), None);
+    assert_eq!(
+        button.get_attribute("aria-labelledby").as_deref(),
+        Some("delete-label")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn sizes_set_padding_and_font_size() {
+    let cases = [
+        (ButtonSize::Small, "5px", "18px"),
+        (ButtonSize::Medium, "8px", "24px"),
+        (ButtonSize::Large, "12px", "28px"),
+    ];
+    for (size, padding, font_size) in cases.iter() {
+        let button = render_icon_button(yew::props!(IconButtonProperties {
+            aria_label: Some("delete".into()),
+            size: *size,
+        }))
+        .await;
+        assert_eq!(computed(&button, "padding-top"), *padding);
+        assert_eq!(computed(&button, "padding-left"), *padding);
+        assert_eq!(computed(&button, "font-size"), *font_size);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn edges_offset_the_padding() {
+    let cases = [
+        (ButtonSize::Small, "-3px"),
+        (ButtonSize::Medium, "-12px"),
+        (ButtonSize::Large, "-12px"),
+    ];
+    for (size, margin) in cases.iter() {
+        let start = render_icon_button(yew::props!(IconButtonProperties {
+            aria_label: Some("menu".into()),
+            size: *size,
+            edge: Some(IconButtonEdge::Start),
+        }))
+        .await;
+        assert_eq!(computed(&start, "margin-left"), *margin);
+        assert_eq!(computed(&start, "margin-right"), "0px");
+
+        let end = render_icon_button(yew::props!(IconButtonProperties {
+            aria_label: Some("more".into()),
+            size: *size,
+            edge: Some(IconButtonEdge::End),
+        }))
+        .await;
+        assert_eq!(computed(&end, "margin-left"), "0px");
+        assert_eq!(computed(&end, "margin-right"), *margin);
+    }
+
+    let no_edge = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("delete".into()),
+    }))
+    .await;
+    assert_eq!(computed(&no_edge, "margin-left"), "0px");
+    assert_eq!(computed(&no_edge, "margin-right"), "0px");
+}
+
+#[wasm_bindgen_test]
+async fn ripples_are_centered_by_default() {
+    let button = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("delete".into()),
+        children: html! { <span style="display: block; width: 24px; height: 24px;" /> },
+    }))
+    .await;
+    // 24px icon with 8px padding on each side
+    assert_eq!(computed(&button, "width"), "40px");
+
+    let rect = button.get_bounding_client_rect();
+    let mut init = MouseEventInit::new();
+    init.client_x((rect.left() + 4.) as i32)
+        .client_y((rect.top() + 4.) as i32)
+        .bubbles(true);
+    let press = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+    button.dispatch_event(&press).unwrap();
+    TimeoutFuture::new(0).await;
+
+    let ripple = button
+        .query_selector(".ripple")
+        .unwrap()
+        .expect("a ripple is shown")
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    let center_x = ripple.offset_left() + ripple.offset_width() / 2;
+    let center_y = ripple.offset_top() + ripple.offset_height() / 2;
+    assert!(
+        (center_x - 20).abs() <= 1 && (center_y - 20).abs() <= 1,
+        "ripple centered at {:?}",
+        (center_x, center_y)
+    );
+}
+
+#[wasm_bindgen_test]
+async fn forwards_the_button_base_properties() {
+    let submit = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("save".into()),
+        name: "action",
+        value: "save",
+        form: "icon-button-form",
+    }))
+    .await;
+    assert_eq!(submit.get_attribute("name").as_deref(), Some("action"));
+    assert_eq!(submit.get_attribute("value").as_deref(), Some("save"));
+    assert_eq!(
+        submit.get_attribute("form").as_deref(),
+        Some("icon-button-form")
+    );
+
+    let download = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("download".into()),
+        href: "#file",
+        download: "file.txt",
+    }))
+    .await;
+    assert_eq!(download.tag_name(), "A");
+    assert_eq!(
+        download.get_attribute("download").as_deref(),
+        Some("file.txt")
+    );
+
+    let span = render_icon_button(yew::props!(IconButtonProperties {
+        aria_label: Some("more".into()),
+        element: "span",
+    }))
+    .await;
+    assert_eq!(span.tag_name(), "SPAN");
+    assert_eq!(span.get_attribute("role").as_deref(), Some("button"));
+}
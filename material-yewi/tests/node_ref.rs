@@ -0,0 +1,128 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use material_yewi::app_bar::AppBar;
+use material_yewi::button::Button;
+use material_yewi::button_base::{ButtonBase, CLASS_FOCUS_VISIBLE};
+use material_yewi::button_group::ButtonGroup;
+use material_yewi::paper::Paper;
+use material_yewi::toolbar::Toolbar;
+use material_yewi::typography::{Typography, TypographyVariant};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::{function_component, html, use_state, Callback, Html, NodeRef, Properties};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(PartialEq, Properties)]
+struct HarnessProps {
+    app_bar: NodeRef,
+    toolbar: NodeRef,
+    button: NodeRef,
+    button_group: NodeRef,
+    typography: NodeRef,
+    paper: NodeRef,
+}
+
+#[function_component]
+fn Harness(props: &HarnessProps) -> Html {
+    html! {
+        <>
+            <AppBar node_ref={&props.app_bar}>
+                <Toolbar node_ref={&props.toolbar}>
+                    <Button node_ref={&props.button}>{"Button"}</Button>
+                </Toolbar>
+            </AppBar>
+            <ButtonGroup node_ref={&props.button_group}>
+                <Button>{"One"}</Button>
+                <Button>{"Two"}</Button>
+            </ButtonGroup>
+            <Paper node_ref={&props.paper}>
+                <Typography node_ref={&props.typography} variant={TypographyVariant::H2}>
+                    {"Title"}
+                </Typography>
+            </Paper>
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn node_refs_are_bound_to_root_elements() {
+    let props = HarnessProps {
+        app_bar: NodeRef::default(),
+        toolbar: NodeRef::default(),
+        button: NodeRef::default(),
+        button_group: NodeRef::default(),
+        typography: NodeRef::default(),
+        paper: NodeRef::default(),
+    };
+    let refs = [
+        (props.app_bar.clone(), "DIV"),
+        (props.toolbar.clone(), "DIV"),
+        (props.button.clone(), "BUTTON"),
+        (props.button_group.clone(), "DIV"),
+        (props.typography.clone(), "H2"),
+        (props.paper.clone(), "DIV"),
+    ];
+
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    yew::Renderer::<Harness>::with_root_and_props(root, props).render();
+    TimeoutFuture::new(0).await;
+
+    for (node_ref, tag_name) in refs.iter() {
+        let element = node_ref.cast::<Element>().expect("ref is bound");
+        assert_eq!(element.tag_name(), *tag_name);
+    }
+    let button = refs[2].0.get().unwrap();
+    let toolbar = refs[1].0.get().unwrap();
+    assert!(toolbar.contains(Some(&button)));
+    let group = refs[3].0.cast::<Element>().unwrap();
+    assert_eq!(group.query_selector_all("button").unwrap().length(), 2);
+}
+
+#[function_component]
+fn FreshRef() -> Html {
+    let renders = use_state(|| 0);
+    let rerender = {
+        let renders = renders.clone();
+        Callback::from(move |_| renders.set(*renders + 1))
+    };
+    html! {
+        <>
+            // a new ref on every render, as when the node_ref prop is left out
+            <ButtonBase node_ref={NodeRef::default()}>{"Fresh"}</ButtonBase>
+            <button id="fresh-ref-rerender" onclick={rerender} />
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn fresh_node_ref_keeps_focus_tracking() {
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    yew::Renderer::<FreshRef>::with_root(root.clone()).render();
+    TimeoutFuture::new(0).await;
+
+    let rerender = root
+        .query_selector("#fresh-ref-rerender")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    rerender.click();
+    rerender.click();
+    TimeoutFuture::new(0).await;
+
+    let button = root
+        .first_element_child()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    // programmatic focus without prior pointer interaction is visible
+    button.focus().unwrap();
+    TimeoutFuture::new(0).await;
+    assert!(button.class_list().contains(CLASS_FOCUS_VISIBLE));
+}
@@ -0,0 +1,168 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::document;
+use material_yewi::toolbar::{Toolbar, ToolbarOverflow};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement, KeyboardEvent, KeyboardEventInit};
+use yew::{function_component, html, Html};
+
+mod common;
+
+use common::mount;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[function_component]
+fn NarrowToolbar() -> Html {
+    // Room for two actions and the "more" button
+    html! {
+        <div style="width: 300px;">
+            <Toolbar overflow={ToolbarOverflow::Menu}>
+                { for (0..5).map(|index| html! {
+                    <button style="box-sizing: border-box; width: 100px; margin: 0; padding: 0; border: 0;">
+                        {format!("Action {}", index)}
+                    </button>
+                }) }
+            </Toolbar>
+        </div>
+    }
+}
+
+async fn render_narrow() -> Element {
+    let root = mount::<NarrowToolbar>(()).await;
+    // measuring takes a few renders
+    TimeoutFuture::new(50).await;
+    root
+}
+
+fn shown_actions(root: &Element) -> u32 {
+    root.query_selector_all("[data-overflow-item] button")
+        .unwrap()
+        .length()
+}
+
+fn more_button(root: &Element) -> Option<HtmlElement> {
+    let more = root.query_selector("[aria-label='More']").unwrap()?;
+    Some(more.dyn_into().unwrap())
+}
+
+fn focused() -> Option<Element> {
+    document().active_element()
+}
+
+#[wasm_bindgen_test]
+async fn overflowing_children_move_into_menu() {
+    let root = render_narrow().await;
+    assert_eq!(
+        shown_actions(&root),
+        2,
+        "children beyond the width are moved"
+    );
+    let more = more_button(&root).expect("a more button is shown");
+    assert_eq!(more.get_attribute("aria-haspopup"), None);
+    assert_eq!(
+        more.get_attribute("aria-expanded").as_deref(),
+        Some("false")
+    );
+    assert_eq!(more.get_attribute("aria-controls"), None);
+    more.click();
+    TimeoutFuture::new(0).await;
+    assert_eq!(more.get_attribute("aria-expanded").as_deref(), Some("true"));
+    let menu_id = more
+        .get_attribute("aria-controls")
+        .expect("controls the open menu");
+    assert!(document().get_element_by_id(&menu_id).is_some());
+
+    let menu_items = root
+        .query_selector_all("[aria-label='More'] + * button")
+        .unwrap();
+    assert_eq!(menu_items.length(), 3);
+    let first = menu_items.item(0).unwrap().text_content().unwrap();
+    assert_eq!(
+        first, "Action 2",
+        "the menu keeps the order of the children"
+    );
+    // Every child is mounted exactly once, either in the toolbar or in the menu
+    for index in 0..5 {
+        let label = format!("Action {}", index);
+        let buttons = root.query_selector_all("button").unwrap();
+        let mounted = (0..buttons.length())
+            .filter_map(|i| buttons.item(i))
+            .filter(|button| button.text_content().as_deref() == Some(label.as_str()))
+            .count();
+        assert_eq!(mounted, 1, "{} is mounted once", label);
+    }
+}
+
+#[wasm_bindgen_test]
+async fn opening_moves_focus_into_the_menu() {
+    let root = render_narrow().await;
+    let more = more_button(&root).unwrap();
+    more.click();
+    TimeoutFuture::new(0).await;
+    let focused = focused().expect("an element is focused");
+    assert_eq!(focused.text_content().as_deref(), Some("Action 2"));
+}
+
+#[wasm_bindgen_test]
+async fn escape_closes_and_returns_focus() {
+    let root = render_narrow().await;
+    let more = more_button(&root).unwrap();
+    more.click();
+    TimeoutFuture::new(0).await;
+
+    let mut init = KeyboardEventInit::new();
+    init.key("Escape").bubbles(true).cancelable(true);
+    let escape = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    focused().unwrap().dispatch_event(&escape).unwrap();
+    TimeoutFuture::new(0).await;
+    assert!(escape.default_prevented());
+    assert_eq!(
+        more.get_attribute("aria-expanded").as_deref(),
+        Some("false")
+    );
+    assert_eq!(focused(), Some(more.into()));
+}
+
+#[wasm_bindgen_test]
+async fn focus_leaving_the_menu_closes_it() {
+    let root = render_narrow().await;
+    let more = more_button(&root).unwrap();
+    more.click();
+    TimeoutFuture::new(0).await;
+    assert_eq!(more.get_attribute("aria-expanded").as_deref(), Some("true"));
+
+    let outside: HtmlElement = root
+        .query_selector("[data-overflow-item] button")
+        .unwrap()
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+    outside.focus().unwrap();
+    TimeoutFuture::new(0).await;
+    assert_eq!(
+        more.get_attribute("aria-expanded").as_deref(),
+        Some("false")
+    );
+    assert_eq!(more.get_attribute("aria-controls"), None);
+}
+
+#[wasm_bindgen_test]
+async fn widening_moves_children_back() {
+    let root = render_narrow().await;
+    assert_eq!(shown_actions(&root), 2);
+
+    let container = root.first_element_child().unwrap();
+    container.set_attribute("style", "width: 800px;").unwrap();
+    // resize observers report with the next frame
+    TimeoutFuture::new(50).await;
+    assert_eq!(shown_actions(&root), 5);
+    assert!(more_button(&root).is_none());
+
+    container.set_attribute("style", "width: 300px;").unwrap();
+    TimeoutFuture::new(50).await;
+    assert_eq!(shown_actions(&root), 2);
+    assert!(more_button(&root).is_some());
+}
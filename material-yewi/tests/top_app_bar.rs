@@ -0,0 +1,135 @@
+#![cfg(target_arch = "wasm32")]
+
+use material_yewi::top_app_bar::{TopAppBar, TopAppBarVariant};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::{function_component, html, Html, Properties};
+
+mod common;
+
+use common::{computed, make_scrollable, render, scroll_window_to};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(PartialEq, Properties)]
+struct HarnessProps {
+    variant: TopAppBarVariant,
+}
+
+#[function_component]
+fn Harness(props: &HarnessProps) -> Html {
+    html! {
+        <TopAppBar
+            variant={props.variant}
+            title={html! { "Title" }}
+            navigation={html! { <button id="navigation">{"Menu"}</button> }}
+            actions={html! { <button id="action">{"Search"}</button> }}
+        />
+    }
+}
+
+async fn render_top_app_bar(variant: TopAppBarVariant) -> Element {
+    render::<Harness>(HarnessProps { variant }).await
+}
+
+/// The navigation, title and actions of the top row
+fn top_row(app_bar: &Element) -> (Element, Element, Element) {
+    let row = app_bar.first_element_child().expect("rendered a top row");
+    let navigation = row.first_element_child().expect("rendered the navigation");
+    let title = navigation.next_element_sibling().expect("rendered a title");
+    let actions = title.next_element_sibling().expect("rendered the actions");
+    (navigation, title, actions)
+}
+
+/// The headline row below the top row, if any
+fn headline(app_bar: &Element) -> Option<Element> {
+    app_bar.first_element_child()?.next_element_sibling()
+}
+
+#[wasm_bindgen_test]
+async fn small_renders_a_single_row() {
+    let app_bar = render_top_app_bar(TopAppBarVariant::Small).await;
+    let (navigation, title, actions) = top_row(&app_bar);
+    assert!(navigation.query_selector("#navigation").unwrap().is_some());
+    assert!(actions.query_selector("#action").unwrap().is_some());
+    assert_eq!(title.text_content().unwrap(), "Title");
+    assert_eq!(title.get_attribute("aria-hidden"), None);
+    assert_eq!(computed(&title, "opacity"), "1");
+    assert_ne!(computed(&title, "position"), "absolute");
+    assert!(headline(&app_bar).is_none());
+}
+
+#[wasm_bindgen_test]
+async fn center_aligned_centers_the_title() {
+    let app_bar = render_top_app_bar(TopAppBarVariant::CenterAligned).await;
+    let (_, title, _) = top_row(&app_bar);
+    assert_eq!(computed(&title, "position"), "absolute");
+    let row = title.parent_element().unwrap().get_bounding_client_rect();
+    let rect = title.get_bounding_client_rect();
+    let row_center = row.left() + row.width() / 2.;
+    let title_center = rect.left() + rect.width() / 2.;
+    assert!(
+        (row_center - title_center).abs() < 1.,
+        "{} != {}",
+        row_center,
+        title_center
+    );
+    assert!(headline(&app_bar).is_none());
+}
+
+#[wasm_bindgen_test]
+async fn medium_and_large_show_a_headline() {
+    for (variant, height) in [
+        (TopAppBarVariant::Medium, "48px"),
+        (TopAppBarVariant::Large, "88px"),
+    ]
+    .iter()
+    {
+        let app_bar = render_top_app_bar(*variant).await;
+        let headline = headline(&app_bar).expect("rendered a headline");
+        assert_eq!(headline.text_content().unwrap(), "Title");
+        assert_eq!(computed(&headline, "height"), *height);
+        // The title of the top row is hidden, it only repeats the headline
+        let (_, title, _) = top_row(&app_bar);
+        assert_eq!(title.get_attribute("aria-hidden").as_deref(), Some("true"));
+        assert_eq!(computed(&title, "opacity"), "0");
+    }
+}
+
+#[wasm_bindgen_test]
+async fn elevates_once_scrolled() {
+    let _spacer = make_scrollable();
+    let app_bar = render_top_app_bar(TopAppBarVariant::Small).await;
+    assert_eq!(computed(&app_bar, "box-shadow"), "none");
+
+    scroll_window_to(10.).await;
+    assert_ne!(computed(&app_bar, "box-shadow"), "none");
+
+    scroll_window_to(0.).await;
+    assert_eq!(computed(&app_bar, "box-shadow"), "none");
+}
+
+#[wasm_bindgen_test]
+async fn headline_collapses_into_the_title() {
+    let _spacer = make_scrollable();
+    let app_bar = render_top_app_bar(TopAppBarVariant::Medium).await;
+
+    // Not yet scrolled past the height of the headline
+    scroll_window_to(20.).await;
+    let (_, title, _) = top_row(&app_bar);
+    assert_eq!(title.get_attribute("aria-hidden").as_deref(), Some("true"));
+    assert_eq!(computed(&headline(&app_bar).unwrap(), "height"), "48px");
+
+    scroll_window_to(100.).await;
+    let (_, title, _) = top_row(&app_bar);
+    assert_eq!(title.get_attribute("aria-hidden"), None);
+    assert_eq!(computed(&title, "opacity"), "1");
+    let headline = headline(&app_bar).expect("keeps the collapsed headline");
+    assert_eq!(computed(&headline, "height"), "0px");
+    assert_eq!(computed(&headline, "opacity"), "0");
+
+    scroll_window_to(0.).await;
+    let (_, title, _) = top_row(&app_bar);
+    assert_eq!(title.get_attribute("aria-hidden").as_deref(), Some("true"));
+    assert_eq!(computed(&headline, "height"), "48px");
+}
@@ -0,0 +1,306 @@
+#![cfg(target_arch = "wasm32")]
+
+use gloo::timers::future::TimeoutFuture;
+use gloo::utils::{document, window};
+use material_styles_yew::{CssColor, Motion, Theme, ThemeContents, ThemeProvider};
+use material_yewi::attributes::ExtraAttributes;
+use material_yewi::button_base::{ButtonBase, RippleBehaviour};
+use material_yewi::touch_ripple::{
+    ImperativeRef, RippleConfig, RippleStartOptions, TouchRipple, TouchRippleHandle,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, Event, HtmlElement, MouseEvent, MouseEventInit, TouchEvent};
+use yew::{function_component, html, Html, Properties};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(PartialEq, Properties)]
+struct HarnessProps {
+    handle: ImperativeRef<TouchRippleHandle>,
+}
+
+#[function_component]
+fn Harness(props: &HarnessProps) -> Html {
+    html! {
+        <div style="position: relative; width: 100px; height: 40px;">
+            <TouchRipple handle={props.handle.clone()} />
+        </div>
+    }
+}
+
+async fn render_harness() -> (Element, ImperativeRef<TouchRippleHandle>) {
+    let handle = ImperativeRef::<TouchRippleHandle>::new();
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let props = HarnessProps {
+        handle: handle.clone(),
+    };
+    yew::Renderer::<Harness>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    (root, handle)
+}
+
+fn count(root: &Element, selector: &str) -> u32 {
+    root.query_selector_all(selector).unwrap().length()
+}
+
+#[wasm_bindgen_test]
+async fn start_and_stop_ripples() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+    assert_eq!(count(&root, ".ripple"), 0);
+
+    let mut init = MouseEventInit::new();
+    init.client_x(10).client_y(10);
+    let press = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+    handle().start(press, RippleStartOptions::default());
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 1);
+
+    handle().stop();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple > .child.childLeaving"), 1);
+
+    // leaving ripples are removed once they faded out
+    TimeoutFuture::new(1000).await;
+    assert_eq!(count(&root, ".ripple"), 0);
+}
+
+#[wasm_bindgen_test]
+async fn pulsating_ripple() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+
+    handle().pulsate();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripplePulsate > .child.childPulsate"), 1);
+
+    handle().stop();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripplePulsate > .child.childLeaving"), 1);
+}
+
+/// A touch without touch points, since not all browsers can construct touch events. Centered
+/// ripples do not look at the touch points.
+fn touch() -> (TouchEvent, RippleStartOptions) {
+    let touch = Event::new("touchstart").unwrap().unchecked_into();
+    let options = RippleStartOptions {
+        center: true,
+        ..Default::default()
+    };
+    (touch, options)
+}
+
+#[wasm_bindgen_test]
+async fn touch_ripples_are_delayed() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+
+    let (touch, options) = touch();
+    handle().start(touch, options);
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple"), 0);
+
+    // the default touch delay is 80ms
+    TimeoutFuture::new(150).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 1);
+}
+
+#[wasm_bindgen_test]
+async fn moving_touch_discards_its_ripple() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+    handle().pulsate();
+    TimeoutFuture::new(0).await;
+
+    let (touch, options) = touch();
+    handle().start(touch, options);
+    // a touch moving to scroll stops the ripple before it is shown
+    handle().stop();
+    TimeoutFuture::new(150).await;
+    assert_eq!(count(&root, ".ripple:not(.ripplePulsate)"), 0);
+    // other ripples are left alone
+    assert_eq!(
+        count(&root, ".ripplePulsate > .child:not(.childLeaving)"),
+        1
+    );
+}
+
+#[wasm_bindgen_test]
+async fn quick_tap_shows_ripple_for_the_touch_delay() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+
+    let (touch, options) = touch();
+    handle().start(touch, options);
+    handle().end_touch();
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 1);
+
+    TimeoutFuture::new(150).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 0);
+    assert_eq!(count(&root, ".ripple > .child.childLeaving"), 1);
+}
+
+#[derive(PartialEq, Properties)]
+struct ButtonHarnessProps {
+    ripples: RippleBehaviour,
+    theme: Theme,
+}
+
+#[function_component]
+fn ButtonHarness(props: &ButtonHarnessProps) -> Html {
+    let attributes = ExtraAttributes::new().attr("style", "width: 100px; height: 40px;");
+    html! {
+        <ThemeProvider context={props.theme.clone()}>
+            <ButtonBase ripples={props.ripples} {attributes}>{"Press"}</ButtonBase>
+        </ThemeProvider>
+    }
+}
+
+async fn render_button(ripples: RippleBehaviour, theme: Theme) -> HtmlElement {
+    let root = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&root).unwrap();
+    let props = ButtonHarnessProps { ripples, theme };
+    yew::Renderer::<ButtonHarness>::with_root_and_props(root.clone(), props).render();
+    TimeoutFuture::new(0).await;
+    root.first_element_child()
+        .expect("rendered a button")
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+}
+
+/// Press the button at an offset from its top left corner
+fn press_at(button: &Element, x: f64, y: f64) {
+    let rect = button.get_bounding_client_rect();
+    let mut init = MouseEventInit::new();
+    init.client_x((rect.left() + x) as i32)
+        .client_y((rect.top() + y) as i32)
+        .bubbles(true);
+    let press = MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap();
+    button.dispatch_event(&press).unwrap();
+}
+
+/// The center of the visible ripple, relative to the button
+fn ripple_center(button: &Element) -> (i32, i32) {
+    let ripple = button
+        .query_selector(".ripple")
+        .unwrap()
+        .expect("a ripple is shown")
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    (
+        ripple.offset_left() + ripple.offset_width() / 2,
+        ripple.offset_top() + ripple.offset_height() / 2,
+    )
+}
+
+fn assert_near((x, y): (i32, i32), (expected_x, expected_y): (i32, i32)) {
+    assert!(
+        (x - expected_x).abs() <= 1 && (y - expected_y).abs() <= 1,
+        "ripple centered at {:?}, expected {:?}",
+        (x, y),
+        (expected_x, expected_y)
+    );
+}
+
+#[wasm_bindgen_test]
+async fn centered_ripples_ignore_the_pointer() {
+    let centered = render_button(RippleBehaviour::Centered, Theme::default()).await;
+    press_at(&centered, 10., 10.);
+    TimeoutFuture::new(0).await;
+    assert_near(ripple_center(&centered), (50, 20));
+
+    let interactive = render_button(RippleBehaviour::Interactive, Theme::default()).await;
+    press_at(&interactive, 10., 10.);
+    TimeoutFuture::new(0).await;
+    assert_near(ripple_center(&interactive), (10, 10));
+}
+
+#[wasm_bindgen_test]
+async fn disabled_ripples_render_no_host() {
+    let disabled = render_button(RippleBehaviour::Disabled, Theme::default()).await;
+    assert_eq!(disabled.child_element_count(), 0);
+    press_at(&disabled, 10., 10.);
+    disabled.focus().unwrap();
+    TimeoutFuture::new(0).await;
+    assert_eq!(disabled.child_element_count(), 0);
+
+    let interactive = render_button(RippleBehaviour::Interactive, Theme::default()).await;
+    assert_eq!(interactive.child_element_count(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn ripple_config_of_the_theme() {
+    let mut contents = ThemeContents {
+        motion: Motion::None,
+        ..Default::default()
+    };
+    contents.components.add_override(RippleConfig {
+        color: Some(CssColor::rgb(255, 0, 0)),
+        opacity: 0.5,
+        duration_ms: 50,
+        ..Default::default()
+    });
+    let button = render_button(RippleBehaviour::Interactive, Theme::from(contents)).await;
+    let computed = |selector: &str, property: &str| {
+        let element = button.query_selector(selector).unwrap().unwrap();
+        let style = window().get_computed_style(&element).unwrap().unwrap();
+        style.get_property_value(property).unwrap()
+    };
+
+    press_at(&button, 10., 10.);
+    TimeoutFuture::new(0).await;
+    assert_eq!(computed(".ripple", "opacity"), "0.5");
+    assert_eq!(
+        computed(".ripple > .child", "background-color"),
+        "rgb(255, 0, 0)"
+    );
+
+    let mut init = MouseEventInit::new();
+    init.bubbles(true);
+    let release = MouseEvent::new_with_mouse_event_init_dict("mouseup", &init).unwrap();
+    button.dispatch_event(&release).unwrap();
+    // removed after the configured duration, well before the default of 550ms
+    TimeoutFuture::new(200).await;
+    assert_eq!(count(&button, ".ripple"), 0);
+}
+
+fn mouse_down() -> MouseEvent {
+    let mut init = MouseEventInit::new();
+    init.client_x(10).client_y(10);
+    MouseEvent::new_with_mouse_event_init_dict("mousedown", &init).unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn mouse_down_emulated_for_a_touch_is_ignored() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+
+    let (touch, options) = touch();
+    handle().start(touch, options);
+    handle().end_touch();
+    TimeoutFuture::new(150).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 0);
+
+    handle().start(mouse_down(), RippleStartOptions::default());
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 0);
+}
+
+#[wasm_bindgen_test]
+async fn mouse_down_long_after_a_touch_starts_a_ripple() {
+    let (root, handle) = render_harness().await;
+    let handle = || handle.get().as_deref().cloned().expect("handle is bound");
+
+    // a cancelled touch is not followed by emulated mouse events
+    let (touch, options) = touch();
+    handle().start(touch, options);
+    handle().stop();
+    TimeoutFuture::new(600).await;
+
+    handle().start(mouse_down(), RippleStartOptions::default());
+    TimeoutFuture::new(0).await;
+    assert_eq!(count(&root, ".ripple > .child:not(.childLeaving)"), 1);
+}
@@ -0,0 +1,79 @@
+#![cfg(target_arch = "wasm32")]
+
+use material_yewi::typography::{Typography, TypographyProperties, TypographyWrap};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::html;
+
+mod common;
+
+use common::{computed, render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn render_typography(props: TypographyProperties) -> Element {
+    render::<Typography>(props).await
+}
+
+fn wrapped(wrap: TypographyWrap) -> TypographyProperties {
+    yew::props!(TypographyProperties {
+        wrap: wrap,
+        children: html! { "Some long text" },
+    })
+}
+
+#[wasm_bindgen_test]
+async fn wrap_modes() {
+    let normal = render_typography(wrapped(TypographyWrap::Normal)).await;
+    assert_eq!(computed(&normal, "overflow-wrap"), "normal");
+    assert_eq!(computed(&normal, "word-break"), "normal");
+
+    let break_word = render_typography(wrapped(TypographyWrap::BreakWord)).await;
+    assert_eq!(computed(&break_word, "overflow-wrap"), "break-word");
+
+    let anywhere = render_typography(wrapped(TypographyWrap::Anywhere)).await;
+    assert_eq!(computed(&anywhere, "overflow-wrap"), "anywhere");
+
+    let break_all = render_typography(wrapped(TypographyWrap::BreakAll)).await;
+    assert_eq!(computed(&break_all, "word-break"), "break-all");
+
+    let hyphenate = render_typography(wrapped(TypographyWrap::Hyphenate)).await;
+    assert_eq!(computed(&hyphenate, "hyphens"), "auto");
+    assert_eq!(computed(&hyphenate, "overflow-wrap"), "break-word");
+}
+
+#[wasm_bindgen_test]
+async fn line_clamp_limits_lines() {
+    let clamped = render_typography(yew::props!(TypographyProperties {
+        line_clamp: 2,
+        children: html! { "Some long text" },
+    }))
+    .await;
+    assert_eq!(computed(&clamped, "-webkit-line-clamp"), "2");
+    assert_eq!(computed(&clamped, "display"), "-webkit-box");
+    assert_eq!(computed(&clamped, "overflow-y"), "hidden");
+    assert_eq!(computed(&clamped, "text-overflow"), "ellipsis");
+}
+
+#[wasm_bindgen_test]
+async fn line_clamp_takes_precedence_over_no_wrap() {
+    let clamped = render_typography(yew::props!(TypographyProperties {
+        line_clamp: 3,
+        no_wrap: true,
+        children: html! { "Some long text" },
+    }))
+    .await;
+    assert_eq!(computed(&clamped, "white-space"), "normal");
+    assert_eq!(computed(&clamped, "-webkit-line-clamp"), "3");
+}
+
+#[wasm_bindgen_test]
+async fn zero_line_clamp_is_unclamped() {
+    let unclamped = render_typography(yew::props!(TypographyProperties {
+        line_clamp: 0,
+        children: html! { "Some long text" },
+    }))
+    .await;
+    assert_eq!(computed(&unclamped, "-webkit-line-clamp"), "none");
+    assert_eq!(computed(&unclamped, "display"), "block");
+}